@@ -0,0 +1,1715 @@
+// Bindings generated by `windows-bindgen` 0.58.0
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+pub mod Talk {
+    pub mod Editor {
+        pub mod Api {
+            windows_core::imp::define_interface!(
+                ITtsControl,
+                ITtsControl_Vtbl,
+                0x674805d6_19fe_4a75_86af_763f1907bc47
+            );
+            impl core::ops::Deref for ITtsControl {
+                type Target = windows::Win32::System::Com::IDispatch;
+                fn deref(&self) -> &Self::Target {
+                    unsafe { core::mem::transmute(self) }
+                }
+            }
+            windows_core::imp::interface_hierarchy!(
+                ITtsControl,
+                windows_core::IUnknown,
+                windows::Win32::System::Com::IDispatch
+            );
+            impl ITtsControl {
+                pub unsafe fn Initialize<P0>(&self, servicename: P0) -> windows_core::Result<()>
+                where
+                    P0: windows_core::Param<windows_core::BSTR>,
+                {
+                    (windows_core::Interface::vtable(self).Initialize)(
+                        windows_core::Interface::as_raw(self),
+                        servicename.param().abi(),
+                    )
+                    .ok()
+                }
+                pub unsafe fn IsInitialized(
+                    &self,
+                ) -> windows_core::Result<windows::Win32::Foundation::VARIANT_BOOL>
+                {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).IsInitialized)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .map(|| result__)
+                }
+                pub unsafe fn GetAvailableHostNames(
+                    &self,
+                ) -> windows_core::Result<*mut windows::Win32::System::Com::SAFEARRAY>
+                {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).GetAvailableHostNames)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .map(|| result__)
+                }
+                pub unsafe fn StartHost(&self) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).StartHost)(
+                        windows_core::Interface::as_raw(self),
+                    )
+                    .ok()
+                }
+                pub unsafe fn TerminateHost(&self) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).TerminateHost)(
+                        windows_core::Interface::as_raw(self),
+                    )
+                    .ok()
+                }
+                pub unsafe fn Connect(&self) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).Connect)(
+                        windows_core::Interface::as_raw(self),
+                    )
+                    .ok()
+                }
+                pub unsafe fn Disconnect(&self) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).Disconnect)(
+                        windows_core::Interface::as_raw(self),
+                    )
+                    .ok()
+                }
+                pub unsafe fn Version(&self) -> windows_core::Result<windows_core::BSTR> {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).Version)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .and_then(|| windows_core::Type::from_abi(result__))
+                }
+                pub unsafe fn Status(
+                    &self,
+                ) -> windows_core::Result<__MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001>
+                {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).Status)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .map(|| result__)
+                }
+                pub unsafe fn MasterControl(&self) -> windows_core::Result<windows_core::BSTR> {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).MasterControl)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .and_then(|| windows_core::Type::from_abi(result__))
+                }
+                pub unsafe fn SetMasterControl<P0>(&self, pretval: P0) -> windows_core::Result<()>
+                where
+                    P0: windows_core::Param<windows_core::BSTR>,
+                {
+                    (windows_core::Interface::vtable(self).SetMasterControl)(
+                        windows_core::Interface::as_raw(self),
+                        pretval.param().abi(),
+                    )
+                    .ok()
+                }
+                pub unsafe fn Text(&self) -> windows_core::Result<windows_core::BSTR> {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).Text)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .and_then(|| windows_core::Type::from_abi(result__))
+                }
+                pub unsafe fn SetText<P0>(&self, pretval: P0) -> windows_core::Result<()>
+                where
+                    P0: windows_core::Param<windows_core::BSTR>,
+                {
+                    (windows_core::Interface::vtable(self).SetText)(
+                        windows_core::Interface::as_raw(self),
+                        pretval.param().abi(),
+                    )
+                    .ok()
+                }
+                pub unsafe fn TextSelectionStart(&self) -> windows_core::Result<i32> {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).TextSelectionStart)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .map(|| result__)
+                }
+                pub unsafe fn SetTextSelectionStart(
+                    &self,
+                    pretval: i32,
+                ) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).SetTextSelectionStart)(
+                        windows_core::Interface::as_raw(self),
+                        pretval,
+                    )
+                    .ok()
+                }
+                pub unsafe fn TextSelectionLength(&self) -> windows_core::Result<i32> {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).TextSelectionLength)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .map(|| result__)
+                }
+                pub unsafe fn SetTextSelectionLength(
+                    &self,
+                    pretval: i32,
+                ) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).SetTextSelectionLength)(
+                        windows_core::Interface::as_raw(self),
+                        pretval,
+                    )
+                    .ok()
+                }
+                pub unsafe fn TextEditMode(
+                    &self,
+                ) -> windows_core::Result<__MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002>
+                {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).TextEditMode)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .map(|| result__)
+                }
+                pub unsafe fn SetTextEditMode(
+                    &self,
+                    pretval: __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002,
+                ) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).SetTextEditMode)(
+                        windows_core::Interface::as_raw(self),
+                        pretval,
+                    )
+                    .ok()
+                }
+                pub unsafe fn Play(&self) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).Play)(windows_core::Interface::as_raw(
+                        self,
+                    ))
+                    .ok()
+                }
+                pub unsafe fn Stop(&self) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).Stop)(windows_core::Interface::as_raw(
+                        self,
+                    ))
+                    .ok()
+                }
+                pub unsafe fn SaveAudioToFile<P0>(&self, path: P0) -> windows_core::Result<()>
+                where
+                    P0: windows_core::Param<windows_core::BSTR>,
+                {
+                    (windows_core::Interface::vtable(self).SaveAudioToFile)(
+                        windows_core::Interface::as_raw(self),
+                        path.param().abi(),
+                    )
+                    .ok()
+                }
+                pub unsafe fn GetPlayTime(&self) -> windows_core::Result<i64> {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).GetPlayTime)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .map(|| result__)
+                }
+                pub unsafe fn GetListCount(&self) -> windows_core::Result<i32> {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).GetListCount)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .map(|| result__)
+                }
+                pub unsafe fn GetListSelectionIndices(
+                    &self,
+                ) -> windows_core::Result<*mut windows::Win32::System::Com::SAFEARRAY>
+                {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).GetListSelectionIndices)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .map(|| result__)
+                }
+                pub unsafe fn GetListSelectionCount(&self) -> windows_core::Result<i32> {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).GetListSelectionCount)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .map(|| result__)
+                }
+                pub unsafe fn SetListSelectionIndex(&self, index: i32) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).SetListSelectionIndex)(
+                        windows_core::Interface::as_raw(self),
+                        index,
+                    )
+                    .ok()
+                }
+                pub unsafe fn SetListSelectionIndices(
+                    &self,
+                    indices: *const windows::Win32::System::Com::SAFEARRAY,
+                ) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).SetListSelectionIndices)(
+                        windows_core::Interface::as_raw(self),
+                        indices,
+                    )
+                    .ok()
+                }
+                pub unsafe fn SetListSelectionRange(
+                    &self,
+                    startindex: i32,
+                    length: i32,
+                ) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).SetListSelectionRange)(
+                        windows_core::Interface::as_raw(self),
+                        startindex,
+                        length,
+                    )
+                    .ok()
+                }
+                pub unsafe fn AddListItem<P0, P1>(
+                    &self,
+                    voicepresetname: P0,
+                    text: P1,
+                ) -> windows_core::Result<()>
+                where
+                    P0: windows_core::Param<windows_core::BSTR>,
+                    P1: windows_core::Param<windows_core::BSTR>,
+                {
+                    (windows_core::Interface::vtable(self).AddListItem)(
+                        windows_core::Interface::as_raw(self),
+                        voicepresetname.param().abi(),
+                        text.param().abi(),
+                    )
+                    .ok()
+                }
+                pub unsafe fn InsertListItem<P0, P1>(
+                    &self,
+                    voicepresetname: P0,
+                    text: P1,
+                ) -> windows_core::Result<()>
+                where
+                    P0: windows_core::Param<windows_core::BSTR>,
+                    P1: windows_core::Param<windows_core::BSTR>,
+                {
+                    (windows_core::Interface::vtable(self).InsertListItem)(
+                        windows_core::Interface::as_raw(self),
+                        voicepresetname.param().abi(),
+                        text.param().abi(),
+                    )
+                    .ok()
+                }
+                pub unsafe fn RemoveListItem(&self) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).RemoveListItem)(
+                        windows_core::Interface::as_raw(self),
+                    )
+                    .ok()
+                }
+                pub unsafe fn ClearListItems(&self) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).ClearListItems)(
+                        windows_core::Interface::as_raw(self),
+                    )
+                    .ok()
+                }
+                pub unsafe fn GetListVoicePreset(
+                    &self,
+                ) -> windows_core::Result<windows_core::BSTR> {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).GetListVoicePreset)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .and_then(|| windows_core::Type::from_abi(result__))
+                }
+                pub unsafe fn SetListVoicePreset<P0>(
+                    &self,
+                    voicepresetname: P0,
+                ) -> windows_core::Result<()>
+                where
+                    P0: windows_core::Param<windows_core::BSTR>,
+                {
+                    (windows_core::Interface::vtable(self).SetListVoicePreset)(
+                        windows_core::Interface::as_raw(self),
+                        voicepresetname.param().abi(),
+                    )
+                    .ok()
+                }
+                pub unsafe fn GetListSentence(&self) -> windows_core::Result<windows_core::BSTR> {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).GetListSentence)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .and_then(|| windows_core::Type::from_abi(result__))
+                }
+                pub unsafe fn SetListSentence<P0, P1>(
+                    &self,
+                    sentence: P0,
+                    synthesize: P1,
+                ) -> windows_core::Result<()>
+                where
+                    P0: windows_core::Param<windows_core::BSTR>,
+                    P1: windows_core::Param<windows::Win32::Foundation::VARIANT_BOOL>,
+                {
+                    (windows_core::Interface::vtable(self).SetListSentence)(
+                        windows_core::Interface::as_raw(self),
+                        sentence.param().abi(),
+                        synthesize.param().abi(),
+                    )
+                    .ok()
+                }
+                pub unsafe fn VoiceNames(
+                    &self,
+                ) -> windows_core::Result<*mut windows::Win32::System::Com::SAFEARRAY>
+                {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).VoiceNames)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .map(|| result__)
+                }
+                pub unsafe fn VoicePresetNames(
+                    &self,
+                ) -> windows_core::Result<*mut windows::Win32::System::Com::SAFEARRAY>
+                {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).VoicePresetNames)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .map(|| result__)
+                }
+                pub unsafe fn CurrentVoicePresetName(
+                    &self,
+                ) -> windows_core::Result<windows_core::BSTR> {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).CurrentVoicePresetName)(
+                        windows_core::Interface::as_raw(self),
+                        &mut result__,
+                    )
+                    .and_then(|| windows_core::Type::from_abi(result__))
+                }
+                pub unsafe fn SetCurrentVoicePresetName<P0>(
+                    &self,
+                    pretval: P0,
+                ) -> windows_core::Result<()>
+                where
+                    P0: windows_core::Param<windows_core::BSTR>,
+                {
+                    (windows_core::Interface::vtable(self).SetCurrentVoicePresetName)(
+                        windows_core::Interface::as_raw(self),
+                        pretval.param().abi(),
+                    )
+                    .ok()
+                }
+                pub unsafe fn GetVoicePreset<P0>(
+                    &self,
+                    presetname: P0,
+                ) -> windows_core::Result<windows_core::BSTR>
+                where
+                    P0: windows_core::Param<windows_core::BSTR>,
+                {
+                    let mut result__ = core::mem::zeroed();
+                    (windows_core::Interface::vtable(self).GetVoicePreset)(
+                        windows_core::Interface::as_raw(self),
+                        presetname.param().abi(),
+                        &mut result__,
+                    )
+                    .and_then(|| windows_core::Type::from_abi(result__))
+                }
+                pub unsafe fn SetVoicePreset<P0>(&self, json: P0) -> windows_core::Result<()>
+                where
+                    P0: windows_core::Param<windows_core::BSTR>,
+                {
+                    (windows_core::Interface::vtable(self).SetVoicePreset)(
+                        windows_core::Interface::as_raw(self),
+                        json.param().abi(),
+                    )
+                    .ok()
+                }
+                pub unsafe fn AddVoicePreset<P0>(&self, json: P0) -> windows_core::Result<()>
+                where
+                    P0: windows_core::Param<windows_core::BSTR>,
+                {
+                    (windows_core::Interface::vtable(self).AddVoicePreset)(
+                        windows_core::Interface::as_raw(self),
+                        json.param().abi(),
+                    )
+                    .ok()
+                }
+                pub unsafe fn ReloadVoicePresets(&self) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).ReloadVoicePresets)(
+                        windows_core::Interface::as_raw(self),
+                    )
+                    .ok()
+                }
+                pub unsafe fn ReloadPhraseDictionary(&self) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).ReloadPhraseDictionary)(
+                        windows_core::Interface::as_raw(self),
+                    )
+                    .ok()
+                }
+                pub unsafe fn ReloadWordDictionary(&self) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).ReloadWordDictionary)(
+                        windows_core::Interface::as_raw(self),
+                    )
+                    .ok()
+                }
+                pub unsafe fn ReloadSymbolDictionary(&self) -> windows_core::Result<()> {
+                    (windows_core::Interface::vtable(self).ReloadSymbolDictionary)(
+                        windows_core::Interface::as_raw(self),
+                    )
+                    .ok()
+                }
+            }
+            #[repr(C)]
+            pub struct ITtsControl_Vtbl {
+                pub base__: windows::Win32::System::Com::IDispatch_Vtbl,
+                pub Initialize: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                ) -> windows_core::HRESULT,
+                pub IsInitialized: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut windows::Win32::Foundation::VARIANT_BOOL,
+                )
+                    -> windows_core::HRESULT,
+                pub GetAvailableHostNames: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut *mut windows::Win32::System::Com::SAFEARRAY,
+                )
+                    -> windows_core::HRESULT,
+                pub StartHost:
+                    unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+                pub TerminateHost:
+                    unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+                pub Connect:
+                    unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+                pub Disconnect:
+                    unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+                pub Version: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                ) -> windows_core::HRESULT,
+                pub Status: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001,
+                ) -> windows_core::HRESULT,
+                pub MasterControl: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                )
+                    -> windows_core::HRESULT,
+                pub SetMasterControl: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                )
+                    -> windows_core::HRESULT,
+                pub Text: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                ) -> windows_core::HRESULT,
+                pub SetText: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                ) -> windows_core::HRESULT,
+                pub TextSelectionStart: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut i32,
+                )
+                    -> windows_core::HRESULT,
+                pub SetTextSelectionStart:
+                    unsafe extern "system" fn(*mut core::ffi::c_void, i32) -> windows_core::HRESULT,
+                pub TextSelectionLength: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut i32,
+                )
+                    -> windows_core::HRESULT,
+                pub SetTextSelectionLength:
+                    unsafe extern "system" fn(*mut core::ffi::c_void, i32) -> windows_core::HRESULT,
+                pub TextEditMode: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002,
+                )
+                    -> windows_core::HRESULT,
+                pub SetTextEditMode: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002,
+                )
+                    -> windows_core::HRESULT,
+                pub Play:
+                    unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+                pub Stop:
+                    unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+                pub SaveAudioToFile: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                )
+                    -> windows_core::HRESULT,
+                pub GetPlayTime: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut i64,
+                )
+                    -> windows_core::HRESULT,
+                pub GetListCount: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut i32,
+                )
+                    -> windows_core::HRESULT,
+                pub GetListSelectionIndices: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut *mut windows::Win32::System::Com::SAFEARRAY,
+                )
+                    -> windows_core::HRESULT,
+                pub GetListSelectionCount: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut i32,
+                )
+                    -> windows_core::HRESULT,
+                pub SetListSelectionIndex:
+                    unsafe extern "system" fn(*mut core::ffi::c_void, i32) -> windows_core::HRESULT,
+                pub SetListSelectionIndices: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *const windows::Win32::System::Com::SAFEARRAY,
+                )
+                    -> windows_core::HRESULT,
+                pub SetListSelectionRange: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    i32,
+                    i32,
+                )
+                    -> windows_core::HRESULT,
+                pub AddListItem: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                )
+                    -> windows_core::HRESULT,
+                pub InsertListItem: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                )
+                    -> windows_core::HRESULT,
+                pub RemoveListItem:
+                    unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+                pub ClearListItems:
+                    unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+                pub GetListVoicePreset: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                )
+                    -> windows_core::HRESULT,
+                pub SetListVoicePreset: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                )
+                    -> windows_core::HRESULT,
+                pub GetListSentence: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                )
+                    -> windows_core::HRESULT,
+                pub SetListSentence: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                    windows::Win32::Foundation::VARIANT_BOOL,
+                )
+                    -> windows_core::HRESULT,
+                pub VoiceNames: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut *mut windows::Win32::System::Com::SAFEARRAY,
+                ) -> windows_core::HRESULT,
+                pub VoicePresetNames: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut *mut windows::Win32::System::Com::SAFEARRAY,
+                )
+                    -> windows_core::HRESULT,
+                pub CurrentVoicePresetName: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                )
+                    -> windows_core::HRESULT,
+                pub SetCurrentVoicePresetName: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                )
+                    -> windows_core::HRESULT,
+                pub GetVoicePreset: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                    *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                )
+                    -> windows_core::HRESULT,
+                pub SetVoicePreset: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                )
+                    -> windows_core::HRESULT,
+                pub AddVoicePreset: unsafe extern "system" fn(
+                    *mut core::ffi::c_void,
+                    core::mem::MaybeUninit<windows_core::BSTR>,
+                )
+                    -> windows_core::HRESULT,
+                pub ReloadVoicePresets:
+                    unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+                pub ReloadPhraseDictionary:
+                    unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+                pub ReloadWordDictionary:
+                    unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+                pub ReloadSymbolDictionary:
+                    unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+            }
+            pub const HostStatus_Busy: __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001 =
+                __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001(3i32);
+            pub const HostStatus_Idle: __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001 =
+                __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001(2i32);
+            pub const HostStatus_NotConnected:
+                __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001 =
+                __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001(1i32);
+            pub const HostStatus_NotRunning:
+                __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001 =
+                __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001(0i32);
+            pub const TextEditMode_List: __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002 =
+                __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002(1i32);
+            pub const TextEditMode_Text: __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002 =
+                __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002(0i32);
+            #[repr(transparent)]
+            #[derive(PartialEq, Eq, Copy, Clone, Default)]
+            pub struct __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001(pub i32);
+            impl windows_core::TypeKind for __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001 {
+                type TypeKind = windows_core::CopyType;
+            }
+            impl core::fmt::Debug for __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001 {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_tuple("__MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001")
+                        .field(&self.0)
+                        .finish()
+                }
+            }
+            #[repr(transparent)]
+            #[derive(PartialEq, Eq, Copy, Clone, Default)]
+            pub struct __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002(pub i32);
+            impl windows_core::TypeKind for __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002 {
+                type TypeKind = windows_core::CopyType;
+            }
+            impl core::fmt::Debug for __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002 {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_tuple("__MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002")
+                        .field(&self.0)
+                        .finish()
+                }
+            }
+            pub const TtsControl: windows_core::GUID =
+                windows_core::GUID::from_u128(0xb628d293_341c_41be_b2e7_9e7822b2b7ac);
+            pub trait ITtsControl_Impl:
+                Sized + windows::Win32::System::Com::IDispatch_Impl
+            {
+                fn Initialize(&self, servicename: &windows_core::BSTR) -> windows_core::Result<()>;
+                fn IsInitialized(
+                    &self,
+                ) -> windows_core::Result<windows::Win32::Foundation::VARIANT_BOOL>;
+                fn GetAvailableHostNames(
+                    &self,
+                ) -> windows_core::Result<*mut windows::Win32::System::Com::SAFEARRAY>;
+                fn StartHost(&self) -> windows_core::Result<()>;
+                fn TerminateHost(&self) -> windows_core::Result<()>;
+                fn Connect(&self) -> windows_core::Result<()>;
+                fn Disconnect(&self) -> windows_core::Result<()>;
+                fn Version(&self) -> windows_core::Result<windows_core::BSTR>;
+                fn Status(
+                    &self,
+                ) -> windows_core::Result<__MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001>;
+                fn MasterControl(&self) -> windows_core::Result<windows_core::BSTR>;
+                fn SetMasterControl(
+                    &self,
+                    pretval: &windows_core::BSTR,
+                ) -> windows_core::Result<()>;
+                fn Text(&self) -> windows_core::Result<windows_core::BSTR>;
+                fn SetText(&self, pretval: &windows_core::BSTR) -> windows_core::Result<()>;
+                fn TextSelectionStart(&self) -> windows_core::Result<i32>;
+                fn SetTextSelectionStart(&self, pretval: i32) -> windows_core::Result<()>;
+                fn TextSelectionLength(&self) -> windows_core::Result<i32>;
+                fn SetTextSelectionLength(&self, pretval: i32) -> windows_core::Result<()>;
+                fn TextEditMode(
+                    &self,
+                ) -> windows_core::Result<__MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002>;
+                fn SetTextEditMode(
+                    &self,
+                    pretval: __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002,
+                ) -> windows_core::Result<()>;
+                fn Play(&self) -> windows_core::Result<()>;
+                fn Stop(&self) -> windows_core::Result<()>;
+                fn SaveAudioToFile(&self, path: &windows_core::BSTR) -> windows_core::Result<()>;
+                fn GetPlayTime(&self) -> windows_core::Result<i64>;
+                fn GetListCount(&self) -> windows_core::Result<i32>;
+                fn GetListSelectionIndices(
+                    &self,
+                ) -> windows_core::Result<*mut windows::Win32::System::Com::SAFEARRAY>;
+                fn GetListSelectionCount(&self) -> windows_core::Result<i32>;
+                fn SetListSelectionIndex(&self, index: i32) -> windows_core::Result<()>;
+                fn SetListSelectionIndices(
+                    &self,
+                    indices: *const windows::Win32::System::Com::SAFEARRAY,
+                ) -> windows_core::Result<()>;
+                fn SetListSelectionRange(
+                    &self,
+                    startindex: i32,
+                    length: i32,
+                ) -> windows_core::Result<()>;
+                fn AddListItem(
+                    &self,
+                    voicepresetname: &windows_core::BSTR,
+                    text: &windows_core::BSTR,
+                ) -> windows_core::Result<()>;
+                fn InsertListItem(
+                    &self,
+                    voicepresetname: &windows_core::BSTR,
+                    text: &windows_core::BSTR,
+                ) -> windows_core::Result<()>;
+                fn RemoveListItem(&self) -> windows_core::Result<()>;
+                fn ClearListItems(&self) -> windows_core::Result<()>;
+                fn GetListVoicePreset(&self) -> windows_core::Result<windows_core::BSTR>;
+                fn SetListVoicePreset(
+                    &self,
+                    voicepresetname: &windows_core::BSTR,
+                ) -> windows_core::Result<()>;
+                fn GetListSentence(&self) -> windows_core::Result<windows_core::BSTR>;
+                fn SetListSentence(
+                    &self,
+                    sentence: &windows_core::BSTR,
+                    synthesize: windows::Win32::Foundation::VARIANT_BOOL,
+                ) -> windows_core::Result<()>;
+                fn VoiceNames(
+                    &self,
+                ) -> windows_core::Result<*mut windows::Win32::System::Com::SAFEARRAY>;
+                fn VoicePresetNames(
+                    &self,
+                ) -> windows_core::Result<*mut windows::Win32::System::Com::SAFEARRAY>;
+                fn CurrentVoicePresetName(&self) -> windows_core::Result<windows_core::BSTR>;
+                fn SetCurrentVoicePresetName(
+                    &self,
+                    pretval: &windows_core::BSTR,
+                ) -> windows_core::Result<()>;
+                fn GetVoicePreset(
+                    &self,
+                    presetname: &windows_core::BSTR,
+                ) -> windows_core::Result<windows_core::BSTR>;
+                fn SetVoicePreset(&self, json: &windows_core::BSTR) -> windows_core::Result<()>;
+                fn AddVoicePreset(&self, json: &windows_core::BSTR) -> windows_core::Result<()>;
+                fn ReloadVoicePresets(&self) -> windows_core::Result<()>;
+                fn ReloadPhraseDictionary(&self) -> windows_core::Result<()>;
+                fn ReloadWordDictionary(&self) -> windows_core::Result<()>;
+                fn ReloadSymbolDictionary(&self) -> windows_core::Result<()>;
+            }
+            impl windows_core::RuntimeName for ITtsControl {}
+            impl ITtsControl_Vtbl {
+                pub const fn new<Identity: windows_core::IUnknownImpl, const OFFSET: isize>(
+                ) -> ITtsControl_Vtbl
+                where
+                    Identity: ITtsControl_Impl,
+                {
+                    unsafe extern "system" fn Initialize<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        servicename: core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::Initialize(this, core::mem::transmute(&servicename))
+                            .into()
+                    }
+                    unsafe extern "system" fn IsInitialized<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut windows::Win32::Foundation::VARIANT_BOOL,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::IsInitialized(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn GetAvailableHostNames<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut *mut windows::Win32::System::Com::SAFEARRAY,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::GetAvailableHostNames(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn StartHost<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::StartHost(this).into()
+                    }
+                    unsafe extern "system" fn TerminateHost<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::TerminateHost(this).into()
+                    }
+                    unsafe extern "system" fn Connect<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::Connect(this).into()
+                    }
+                    unsafe extern "system" fn Disconnect<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::Disconnect(this).into()
+                    }
+                    unsafe extern "system" fn Version<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::Version(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn Status<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0001,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::Status(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn MasterControl<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::MasterControl(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn SetMasterControl<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::SetMasterControl(this, core::mem::transmute(&pretval))
+                            .into()
+                    }
+                    unsafe extern "system" fn Text<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::Text(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn SetText<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::SetText(this, core::mem::transmute(&pretval)).into()
+                    }
+                    unsafe extern "system" fn TextSelectionStart<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut i32,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::TextSelectionStart(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn SetTextSelectionStart<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: i32,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::SetTextSelectionStart(
+                            this,
+                            core::mem::transmute_copy(&pretval),
+                        )
+                        .into()
+                    }
+                    unsafe extern "system" fn TextSelectionLength<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut i32,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::TextSelectionLength(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn SetTextSelectionLength<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: i32,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::SetTextSelectionLength(
+                            this,
+                            core::mem::transmute_copy(&pretval),
+                        )
+                        .into()
+                    }
+                    unsafe extern "system" fn TextEditMode<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::TextEditMode(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn SetTextEditMode<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: __MIDL___MIDL_itf_AI2ETalk2EEditor2EApi_0000_0000_0002,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::SetTextEditMode(this, core::mem::transmute_copy(&pretval))
+                            .into()
+                    }
+                    unsafe extern "system" fn Play<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::Play(this).into()
+                    }
+                    unsafe extern "system" fn Stop<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::Stop(this).into()
+                    }
+                    unsafe extern "system" fn SaveAudioToFile<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        path: core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::SaveAudioToFile(this, core::mem::transmute(&path)).into()
+                    }
+                    unsafe extern "system" fn GetPlayTime<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut i64,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::GetPlayTime(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn GetListCount<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut i32,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::GetListCount(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn GetListSelectionIndices<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut *mut windows::Win32::System::Com::SAFEARRAY,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::GetListSelectionIndices(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn GetListSelectionCount<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut i32,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::GetListSelectionCount(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn SetListSelectionIndex<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        index: i32,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::SetListSelectionIndex(
+                            this,
+                            core::mem::transmute_copy(&index),
+                        )
+                        .into()
+                    }
+                    unsafe extern "system" fn SetListSelectionIndices<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        indices: *const windows::Win32::System::Com::SAFEARRAY,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::SetListSelectionIndices(
+                            this,
+                            core::mem::transmute_copy(&indices),
+                        )
+                        .into()
+                    }
+                    unsafe extern "system" fn SetListSelectionRange<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        startindex: i32,
+                        length: i32,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::SetListSelectionRange(
+                            this,
+                            core::mem::transmute_copy(&startindex),
+                            core::mem::transmute_copy(&length),
+                        )
+                        .into()
+                    }
+                    unsafe extern "system" fn AddListItem<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        voicepresetname: core::mem::MaybeUninit<windows_core::BSTR>,
+                        text: core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::AddListItem(
+                            this,
+                            core::mem::transmute(&voicepresetname),
+                            core::mem::transmute(&text),
+                        )
+                        .into()
+                    }
+                    unsafe extern "system" fn InsertListItem<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        voicepresetname: core::mem::MaybeUninit<windows_core::BSTR>,
+                        text: core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::InsertListItem(
+                            this,
+                            core::mem::transmute(&voicepresetname),
+                            core::mem::transmute(&text),
+                        )
+                        .into()
+                    }
+                    unsafe extern "system" fn RemoveListItem<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::RemoveListItem(this).into()
+                    }
+                    unsafe extern "system" fn ClearListItems<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::ClearListItems(this).into()
+                    }
+                    unsafe extern "system" fn GetListVoicePreset<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::GetListVoicePreset(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn SetListVoicePreset<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        voicepresetname: core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::SetListVoicePreset(
+                            this,
+                            core::mem::transmute(&voicepresetname),
+                        )
+                        .into()
+                    }
+                    unsafe extern "system" fn GetListSentence<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::GetListSentence(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn SetListSentence<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        sentence: core::mem::MaybeUninit<windows_core::BSTR>,
+                        synthesize: windows::Win32::Foundation::VARIANT_BOOL,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::SetListSentence(
+                            this,
+                            core::mem::transmute(&sentence),
+                            core::mem::transmute_copy(&synthesize),
+                        )
+                        .into()
+                    }
+                    unsafe extern "system" fn VoiceNames<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut *mut windows::Win32::System::Com::SAFEARRAY,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::VoiceNames(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn VoicePresetNames<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut *mut windows::Win32::System::Com::SAFEARRAY,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::VoicePresetNames(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn CurrentVoicePresetName<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::CurrentVoicePresetName(this) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn SetCurrentVoicePresetName<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        pretval: core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::SetCurrentVoicePresetName(
+                            this,
+                            core::mem::transmute(&pretval),
+                        )
+                        .into()
+                    }
+                    unsafe extern "system" fn GetVoicePreset<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        presetname: core::mem::MaybeUninit<windows_core::BSTR>,
+                        pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        match ITtsControl_Impl::GetVoicePreset(
+                            this,
+                            core::mem::transmute(&presetname),
+                        ) {
+                            Ok(ok__) => {
+                                pretval.write(core::mem::transmute(ok__));
+                                windows_core::HRESULT(0)
+                            }
+                            Err(err) => err.into(),
+                        }
+                    }
+                    unsafe extern "system" fn SetVoicePreset<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        json: core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::SetVoicePreset(this, core::mem::transmute(&json)).into()
+                    }
+                    unsafe extern "system" fn AddVoicePreset<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                        json: core::mem::MaybeUninit<windows_core::BSTR>,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::AddVoicePreset(this, core::mem::transmute(&json)).into()
+                    }
+                    unsafe extern "system" fn ReloadVoicePresets<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::ReloadVoicePresets(this).into()
+                    }
+                    unsafe extern "system" fn ReloadPhraseDictionary<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::ReloadPhraseDictionary(this).into()
+                    }
+                    unsafe extern "system" fn ReloadWordDictionary<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::ReloadWordDictionary(this).into()
+                    }
+                    unsafe extern "system" fn ReloadSymbolDictionary<
+                        Identity: windows_core::IUnknownImpl,
+                        const OFFSET: isize,
+                    >(
+                        this: *mut core::ffi::c_void,
+                    ) -> windows_core::HRESULT
+                    where
+                        Identity: ITtsControl_Impl,
+                    {
+                        let this: &Identity =
+                            &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                        ITtsControl_Impl::ReloadSymbolDictionary(this).into()
+                    }
+                    Self {
+                        base__: windows::Win32::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(
+                        ),
+                        Initialize: Initialize::<Identity, OFFSET>,
+                        IsInitialized: IsInitialized::<Identity, OFFSET>,
+                        GetAvailableHostNames: GetAvailableHostNames::<Identity, OFFSET>,
+                        StartHost: StartHost::<Identity, OFFSET>,
+                        TerminateHost: TerminateHost::<Identity, OFFSET>,
+                        Connect: Connect::<Identity, OFFSET>,
+                        Disconnect: Disconnect::<Identity, OFFSET>,
+                        Version: Version::<Identity, OFFSET>,
+                        Status: Status::<Identity, OFFSET>,
+                        MasterControl: MasterControl::<Identity, OFFSET>,
+                        SetMasterControl: SetMasterControl::<Identity, OFFSET>,
+                        Text: Text::<Identity, OFFSET>,
+                        SetText: SetText::<Identity, OFFSET>,
+                        TextSelectionStart: TextSelectionStart::<Identity, OFFSET>,
+                        SetTextSelectionStart: SetTextSelectionStart::<Identity, OFFSET>,
+                        TextSelectionLength: TextSelectionLength::<Identity, OFFSET>,
+                        SetTextSelectionLength: SetTextSelectionLength::<Identity, OFFSET>,
+                        TextEditMode: TextEditMode::<Identity, OFFSET>,
+                        SetTextEditMode: SetTextEditMode::<Identity, OFFSET>,
+                        Play: Play::<Identity, OFFSET>,
+                        Stop: Stop::<Identity, OFFSET>,
+                        SaveAudioToFile: SaveAudioToFile::<Identity, OFFSET>,
+                        GetPlayTime: GetPlayTime::<Identity, OFFSET>,
+                        GetListCount: GetListCount::<Identity, OFFSET>,
+                        GetListSelectionIndices: GetListSelectionIndices::<Identity, OFFSET>,
+                        GetListSelectionCount: GetListSelectionCount::<Identity, OFFSET>,
+                        SetListSelectionIndex: SetListSelectionIndex::<Identity, OFFSET>,
+                        SetListSelectionIndices: SetListSelectionIndices::<Identity, OFFSET>,
+                        SetListSelectionRange: SetListSelectionRange::<Identity, OFFSET>,
+                        AddListItem: AddListItem::<Identity, OFFSET>,
+                        InsertListItem: InsertListItem::<Identity, OFFSET>,
+                        RemoveListItem: RemoveListItem::<Identity, OFFSET>,
+                        ClearListItems: ClearListItems::<Identity, OFFSET>,
+                        GetListVoicePreset: GetListVoicePreset::<Identity, OFFSET>,
+                        SetListVoicePreset: SetListVoicePreset::<Identity, OFFSET>,
+                        GetListSentence: GetListSentence::<Identity, OFFSET>,
+                        SetListSentence: SetListSentence::<Identity, OFFSET>,
+                        VoiceNames: VoiceNames::<Identity, OFFSET>,
+                        VoicePresetNames: VoicePresetNames::<Identity, OFFSET>,
+                        CurrentVoicePresetName: CurrentVoicePresetName::<Identity, OFFSET>,
+                        SetCurrentVoicePresetName: SetCurrentVoicePresetName::<Identity, OFFSET>,
+                        GetVoicePreset: GetVoicePreset::<Identity, OFFSET>,
+                        SetVoicePreset: SetVoicePreset::<Identity, OFFSET>,
+                        AddVoicePreset: AddVoicePreset::<Identity, OFFSET>,
+                        ReloadVoicePresets: ReloadVoicePresets::<Identity, OFFSET>,
+                        ReloadPhraseDictionary: ReloadPhraseDictionary::<Identity, OFFSET>,
+                        ReloadWordDictionary: ReloadWordDictionary::<Identity, OFFSET>,
+                        ReloadSymbolDictionary: ReloadSymbolDictionary::<Identity, OFFSET>,
+                    }
+                }
+                pub fn matches(iid: &windows_core::GUID) -> bool {
+                    iid == & < ITtsControl < > as windows_core::Interface >::IID || iid == & < windows::Win32::System::Com:: IDispatch as windows_core::Interface >::IID
+                }
+            }
+        }
+    }
+}
@@ -1,15 +1,42 @@
-use std::{cmp::PartialEq, ffi::c_void, sync::Arc};
+use std::{
+    cell::RefCell,
+    cmp::PartialEq,
+    ffi::c_void,
+    fmt,
+    hash::{DefaultHasher, Hash, Hasher},
+    mem::ManuallyDrop,
+    path::{Path, PathBuf},
+    process::Command,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
-use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use windows::{
     core::BSTR,
-    Win32::System::{Com::*, Ole::*, Variant::*},
+    Win32::{
+        Foundation::E_OUTOFMEMORY,
+        System::{Com::*, Ole::*, Variant::*},
+    },
 };
 
 use ai_voice_sys::{ITtsControl, TtsControl};
 
-#[derive(Debug, PartialEq)]
+use crate::{
+    error, eventlog, host, lenient,
+    limits::{check_list_rows, check_text_len, ContentLimits},
+    list::check_list_range,
+    preset::{PresetCache, PRESET_CACHE_CAPACITY},
+    stall::{PlaybackWaiter, StallWatchdog},
+    AiVoiceBuilder, AiVoiceError, LenientParse, ListItem, Result, RetryPolicy, Version,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[doc = "ホストプログラムの状態"]
 pub enum HostStatus {
     #[doc = "起動していない"]
@@ -22,7 +49,7 @@ pub enum HostStatus {
     Busy,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[doc = "テキスト入力形式"]
 pub enum TextEditMode {
     #[doc = "テキスト形式"]
@@ -31,7 +58,18 @@ pub enum TextEditMode {
     List,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[doc = "音声保存時の出力先ファイルが既に存在する場合の動作"]
+pub enum OverwritePolicy {
+    #[doc = "ホストプログラムにそのまま保存させる"]
+    Overwrite,
+    #[doc = "保存せずに `AiVoiceError::FileExists` を返す"]
+    Skip,
+    #[doc = "ファイル名に連番を付加した新しいパスに保存する"]
+    Rename,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 #[doc = "マスターコントロール"]
 pub struct MasterControl {
@@ -51,7 +89,7 @@ pub struct MasterControl {
     pub sentence_pause: u16,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 #[doc = "ボイスプリセット"]
 pub struct VoicePreset {
@@ -77,7 +115,7 @@ pub struct VoicePreset {
     pub merged_voice_container: MergedVoiceContainer,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 #[doc = "フュージョン情報"]
 pub struct MergedVoiceContainer {
@@ -87,14 +125,48 @@ pub struct MergedVoiceContainer {
     pub merged_voices: Vec<MergedVoice>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct MergedVoice {
     #[doc = "ボイス名"]
     pub voice_name: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[doc = "スタイル名"]
+pub enum StyleName {
+    #[doc = "喜び（ハイテンション）"]
+    Joy,
+    #[doc = "怒り"]
+    Anger,
+    #[doc = "悲しみ（ローテンション）"]
+    Sadness,
+}
+
+impl StyleName {
+    /// ホストプログラムで使用されるスタイル名の文字列から変換します。
+    ///
+    pub fn from_code(code: &str) -> Option<StyleName> {
+        match code {
+            "J" => Some(StyleName::Joy),
+            "A" => Some(StyleName::Anger),
+            "S" => Some(StyleName::Sadness),
+            _ => None,
+        }
+    }
+
+    /// ホストプログラムで使用されるスタイル名の文字列に変換します。
+    ///
+    pub fn code(&self) -> &'static str {
+        match self {
+            StyleName::Joy => "J",
+            StyleName::Anger => "A",
+            StyleName::Sadness => "S",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Style {
     /// スタイル名
@@ -109,51 +181,604 @@ pub struct Style {
     pub value: f64,
 }
 
-#[derive(Clone)]
-pub struct AiVoice {
-    control: Arc<ITtsControl>,
+impl HostStatus {
+    /// COM API の値から `HostStatus` を取得します。
+    ///
+    /// # 戻り値
+    /// 未知の値の場合は `None`
+    ///
+    pub fn from_raw(value: i32) -> Option<HostStatus> {
+        match value {
+            0 => Some(HostStatus::NotRunning),
+            1 => Some(HostStatus::NotConnected),
+            2 => Some(HostStatus::Idle),
+            3 => Some(HostStatus::Busy),
+            _ => None,
+        }
+    }
+
+    /// COM API の値を取得します。
+    ///
+    pub fn to_raw(&self) -> i32 {
+        match self {
+            HostStatus::NotRunning => 0,
+            HostStatus::NotConnected => 1,
+            HostStatus::Idle => 2,
+            HostStatus::Busy => 3,
+        }
+    }
+
+    /// 次のポーリングで `next` の状態に遷移することが妥当かどうかを判定します。
+    ///
+    /// ホストプログラムは起動後に未接続状態を経由してから待機中になるため、
+    /// 未起動状態から待機中・処理中への遷移や、未接続状態から処理中への遷移は不正とみなします。
+    /// 同じ状態のままであることは常に妥当です。
+    ///
+    /// # 引数
+    /// * `next` - 遷移先の状態
+    ///
+    pub fn can_transition_to(&self, next: &HostStatus) -> bool {
+        !matches!(
+            (self, next),
+            (HostStatus::NotRunning, HostStatus::Idle)
+                | (HostStatus::NotRunning, HostStatus::Busy)
+                | (HostStatus::NotConnected, HostStatus::Busy)
+        )
+    }
+}
+
+impl TextEditMode {
+    /// COM API の値から `TextEditMode` を取得します。
+    ///
+    /// # 戻り値
+    /// 未知の値の場合は `None`
+    ///
+    pub fn from_raw(value: i32) -> Option<TextEditMode> {
+        match value {
+            0 => Some(TextEditMode::Text),
+            1 => Some(TextEditMode::List),
+            _ => None,
+        }
+    }
+
+    /// COM API の値を取得します。
+    ///
+    pub fn to_raw(&self) -> i32 {
+        match self {
+            TextEditMode::Text => 0,
+            TextEditMode::List => 1,
+        }
+    }
+}
+
+pub(crate) fn host_status_from_sys(host_status: ai_voice_sys::HostStatus) -> Result<HostStatus> {
+    HostStatus::from_raw(host_status.0).ok_or_else(|| AiVoiceError::UnknownStatus(host_status.0))
+}
+
+/// 状態遷移が不正な場合に警告を記録します。
+///
+/// `eventlog` 機能が有効な場合はイベントログに、`metrics` 機能が有効な場合はカウンタに記録します。
+pub(crate) fn check_transition(method: &str, previous: HostStatus, next: HostStatus) {
+    if previous.can_transition_to(&next) {
+        return;
+    }
+
+    eventlog::warn(&format!(
+        "{}: unexpected host status transition from {:?} to {:?}",
+        method, previous, next
+    ));
+
+    #[cfg(feature = "metrics")]
+    metrics::counter!("ai_voice.unexpected_transition", "method" => method.to_string())
+        .increment(1);
+}
+
+/// ホストプログラムが扱う UTF-16 のコード単位での文字列長を求めます。
+fn utf16_len(s: &str) -> i32 {
+    s.encode_utf16().count() as i32
+}
+
+/// UNIX エポックからの経過ミリ秒を求めます。
+fn epoch_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as u64)
+}
+
+/// 既存のファイルと重複しないよう、ファイル名に連番を付加したパスを求めます。
+fn unique_path(path: &Path) -> PathBuf {
+    if !path.exists() {
+        return path.to_path_buf();
+    }
+
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = path.extension().map(|e| e.to_string_lossy());
+
+    (1..)
+        .map(|i| {
+            let file_name = match &extension {
+                Some(extension) => format!("{}_{}.{}", stem, i, extension),
+                None => format!("{}_{}", stem, i),
+            };
+            path.with_file_name(file_name)
+        })
+        .find(|candidate| !candidate.exists())
+        .unwrap()
+}
+
+/// 1次元 SafeArray の全要素を読み取り、`convert` で変換したベクターを返します。
+///
+/// 下限から上限 `SafeArrayGetUBound` までを、上限を含めて読み取ります。
+/// 要素を持たない SafeArray では上限が下限より小さくなるため、空のベクターを返します。
+///
+/// # 注意
+/// `psa` は要素の型が `T` と一致する有効な1次元 SafeArray である必要があります。
+///
+unsafe fn read_safearray<T: Default, U>(
+    psa: *const SAFEARRAY,
+    convert: impl Fn(T) -> U,
+) -> Result<Vec<U>> {
+    let lob = SafeArrayGetLBound(psa, 1)?;
+    let upb = SafeArrayGetUBound(psa, 1)?;
+
+    let mut elements = Vec::with_capacity((upb - lob + 1).max(0) as usize);
+    for i in lob..=upb {
+        let mut data = T::default();
+        SafeArrayGetElement(psa, &i, &mut data as *mut T as *mut c_void)?;
+        elements.push(convert(data));
+    }
+
+    Ok(elements)
+}
+
+/// `BSTR` の1次元 SafeArray を文字列のベクターに変換します。
+unsafe fn read_bstr_safearray(psa: *const SAFEARRAY) -> Result<Vec<String>> {
+    read_safearray(psa, |data: BSTR| data.to_string())
+}
+
+/// 次に生成する `AiVoice` のセッション ID
+static NEXT_SESSION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// 登録されたコールバックのリスト
+type Callbacks = Vec<Rc<dyn Fn()>>;
+
+/// 複製したインスタンスで共有する `ITtsControl` と COM の初期化状態
+///
+/// `ITtsControl` はアパートメントに結び付いておりスレッド間で共有できないため、`Rc` で保持します。
+pub(crate) struct Inner {
+    pub(crate) control: ManuallyDrop<ITtsControl>,
+    manages_com: bool,
 }
 
-impl Drop for AiVoice {
+impl Drop for Inner {
     fn drop(&mut self) {
         unsafe {
-            CoUninitialize();
+            // `CoUninitialize` の前に `ITtsControl` を解放する
+            ManuallyDrop::drop(&mut self.control);
+
+            if self.manages_com {
+                CoUninitialize();
+            }
         }
     }
 }
 
+/// `SharedAiVoice` で別のスレッドの `AiVoice` に引き継ぐ設定と状態
+///
+/// `on_presets_reloaded` のコールバックは `Send` ではないため含まれません。
+#[derive(Clone)]
+pub(crate) struct SharedParts {
+    initialized: Arc<AtomicBool>,
+    ever_connected: Arc<AtomicBool>,
+    last_text: Arc<Mutex<Option<String>>>,
+    play_time: Arc<Mutex<Option<(u64, i64)>>>,
+    strict_play: bool,
+    poll_interval: Duration,
+    version: Arc<Mutex<Option<Version>>>,
+    last_activity: Arc<AtomicU64>,
+    auto_disconnect_timeout: Duration,
+    retry_policy: RetryPolicy,
+    restore_text_edit_mode: bool,
+    stall_timeout: Option<Duration>,
+    content_limits: Option<ContentLimits>,
+    host_executable: Option<PathBuf>,
+    preset_cache: Arc<Mutex<PresetCache>>,
+    session_id: u64,
+}
+
+/// A.I.VOICE Editor API のクライアント
+///
+/// ホストプログラムの呼び出しに失敗したとき、ホストプログラムが起動していない場合は
+/// `AiVoiceError::HostNotRunning` を、接続していない場合は `AiVoiceError::NotConnected` を返します。
+///
+/// 複製したインスタンスは同じ `ITtsControl` を共有します。
+/// 複製時は参照カウントが増やされるだけで、新たな接続は作成されません。
+/// `CoUninitialize` は最後のインスタンスが破棄されたときに1回だけ呼び出されます。
+///
+#[derive(Clone)]
+pub struct AiVoice {
+    pub(crate) inner: Rc<Inner>,
+    initialized: Arc<AtomicBool>,
+    ever_connected: Arc<AtomicBool>,
+    last_text: Arc<Mutex<Option<String>>>,
+    play_time: Arc<Mutex<Option<(u64, i64)>>>,
+    strict_play: bool,
+    pub(crate) poll_interval: Duration,
+    pub(crate) version: Arc<Mutex<Option<Version>>>,
+    last_activity: Arc<AtomicU64>,
+    auto_disconnect_timeout: Duration,
+    pub(crate) retry_policy: RetryPolicy,
+    restore_text_edit_mode: bool,
+    pub(crate) stall_timeout: Option<Duration>,
+    content_limits: Option<ContentLimits>,
+    host_executable: Option<PathBuf>,
+    presets_reloaded: Rc<RefCell<Callbacks>>,
+    preset_cache: Arc<Mutex<PresetCache>>,
+    session_id: u64,
+}
+
+/// COM を呼び出さずに、キャッシュされた情報のみを出力します。
+impl fmt::Debug for AiVoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AiVoice")
+            .field("session_id", &self.session_id)
+            .field("ever_connected", &self.has_ever_connected())
+            .field("manages_com", &self.inner.manages_com)
+            .finish_non_exhaustive()
+    }
+}
+
 impl AiVoice {
     pub fn new() -> Result<Self> {
+        AiVoiceBuilder::new().build()
+    }
+
+    /// ホストプログラムと接続済みの `AiVoice` を生成します。
+    ///
+    /// ホストプログラムが起動していない場合は起動して起動完了まで待機し、
+    /// 接続していない場合は接続します。
+    /// ホストプログラムが既に起動・接続済みの場合は何もしません。
+    ///
+    /// # エラー
+    /// ホストプログラムが30秒以内に起動しない場合や、
+    /// `AiVoiceBuilder::retry_policy` の方針（既定では500ミリ秒間隔で5回）で
+    /// 接続を試行しても失敗する場合にエラーを返します。
+    ///
+    pub fn ready() -> Result<Self> {
+        let ai_voice = Self::new()?;
+        ai_voice.ensure_ready()?;
+        Ok(ai_voice)
+    }
+
+    pub(crate) fn ensure_ready(&self) -> Result<()> {
+        if self.status()? == HostStatus::NotRunning {
+            self.start_host_and_wait(Duration::from_secs(30))?;
+        }
+
+        if self.status()? == HostStatus::NotConnected {
+            self.retry_policy.run(|| self.connect())?;
+        }
+
+        Ok(())
+    }
+
+    /// `AiVoice` の生成時の設定を指定するビルダーを返します。
+    ///
+    pub fn builder() -> AiVoiceBuilder {
+        AiVoiceBuilder::new()
+    }
+
+    pub(crate) fn from_builder(builder: &AiVoiceBuilder) -> Result<Self> {
         unsafe {
-            CoInitializeEx(None, COINIT_MULTITHREADED).ok()?;
+            eventlog::report(
+                "new",
+                CoInitializeEx(None, builder.apartment.to_coinit()).ok(),
+            )
+            .map_err(AiVoiceError::ComInit)?;
+
+            let control = match Self::create_control(builder.host_name.as_deref()) {
+                Ok(control) => control,
+                Err(e) => {
+                    CoUninitialize();
+                    return Err(e);
+                }
+            };
+
+            // `create_control` で `Initialize` が成功しているため、初期化済みとして扱う
+            Ok(Self::with_control(
+                control,
+                builder,
+                !builder.leak_com,
+                true,
+            ))
+        }
+    }
 
-            let control: ITtsControl = CoCreateInstance(&TtsControl, None, CLSCTX_INPROC_SERVER)?;
+    /// 既存の `ITtsControl` から `AiVoice` を作成します。
+    ///
+    /// # 引数
+    /// * `control` - 使用する `ITtsControl`
+    /// * `manages_com` - 最後のインスタンスの `Drop` 時に `CoUninitialize` を呼び出すかどうか
+    ///
+    /// # 注意
+    /// 呼び出し側は次の条件を満たす必要があります。
+    /// - `control` は `Initialize` でホストプログラムを指定して初期化済みであること
+    /// - `AiVoice` を使用するスレッドで、`control` を作成したアパートメントと同じアパートメントで
+    ///   COM が初期化されていること
+    /// - `manages_com` が `true` の場合、このスレッドで `CoInitializeEx` を呼び出し済みで、
+    ///   対応する `CoUninitialize` の呼び出しを `AiVoice` に委ねること
+    ///
+    /// `AiVoiceBuilder` の設定項目には既定値が使用されます。
+    /// `control` が初期化済みかどうかは、初期化が必要な操作を最初に行った時点で
+    /// `IsInitialized` によって確認され、初期化されていない場合は `AiVoiceError::NotInitialized` が返されます。
+    ///
+    pub fn from_control(control: ITtsControl, manages_com: bool) -> Self {
+        Self::with_control(control, &AiVoiceBuilder::default(), manages_com, false)
+    }
 
-            let mut host_name = BSTR::default();
-            SafeArrayGetElement(
-                control.GetAvailableHostNames()?,
-                &0,
-                &mut host_name as *mut BSTR as *mut c_void,
-            )?;
+    fn with_control(
+        control: ITtsControl,
+        builder: &AiVoiceBuilder,
+        manages_com: bool,
+        initialized: bool,
+    ) -> Self {
+        AiVoice {
+            inner: Rc::new(Inner {
+                control: ManuallyDrop::new(control),
+                manages_com,
+            }),
+            initialized: Arc::new(AtomicBool::new(initialized)),
+            ever_connected: Arc::new(AtomicBool::new(false)),
+            last_text: Arc::new(Mutex::new(None)),
+            play_time: Arc::new(Mutex::new(None)),
+            strict_play: builder.strict_play,
+            poll_interval: builder.poll_interval,
+            version: Arc::new(Mutex::new(None)),
+            last_activity: Arc::new(AtomicU64::new(epoch_millis(SystemTime::now()))),
+            auto_disconnect_timeout: builder.auto_disconnect_timeout,
+            retry_policy: builder.retry_policy,
+            restore_text_edit_mode: builder.restore_text_edit_mode,
+            stall_timeout: builder.stall_timeout,
+            content_limits: builder.check_content_size.then_some(builder.content_limits),
+            host_executable: builder.host_executable.clone(),
+            presets_reloaded: Rc::new(RefCell::new(Vec::new())),
+            preset_cache: Arc::new(Mutex::new(PresetCache::new(PRESET_CACHE_CAPACITY))),
+            session_id: NEXT_SESSION_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
 
-            control.Initialize(&host_name)?;
+    /// 別のスレッドに引き継ぐ設定と状態を取得します。
+    pub(crate) fn shared_parts(&self) -> SharedParts {
+        SharedParts {
+            initialized: self.initialized.clone(),
+            ever_connected: self.ever_connected.clone(),
+            last_text: self.last_text.clone(),
+            play_time: self.play_time.clone(),
+            strict_play: self.strict_play,
+            poll_interval: self.poll_interval,
+            version: self.version.clone(),
+            last_activity: self.last_activity.clone(),
+            auto_disconnect_timeout: self.auto_disconnect_timeout,
+            retry_policy: self.retry_policy,
+            restore_text_edit_mode: self.restore_text_edit_mode,
+            stall_timeout: self.stall_timeout,
+            content_limits: self.content_limits,
+            host_executable: self.host_executable.clone(),
+            preset_cache: self.preset_cache.clone(),
+            session_id: self.session_id,
+        }
+    }
 
-            Ok(AiVoice {
-                control: Arc::new(control),
-            })
+    /// `shared_parts` で取得した設定と状態を引き継いで、別のスレッドで `AiVoice` を作成します。
+    ///
+    /// `control` はこのスレッドのアパートメント向けに取得したものを渡します。
+    /// コールバックは引き継がれません。
+    pub(crate) fn from_shared_parts(
+        control: ITtsControl,
+        parts: SharedParts,
+        manages_com: bool,
+    ) -> Self {
+        AiVoice {
+            inner: Rc::new(Inner {
+                control: ManuallyDrop::new(control),
+                manages_com,
+            }),
+            initialized: parts.initialized,
+            ever_connected: parts.ever_connected,
+            last_text: parts.last_text,
+            play_time: parts.play_time,
+            strict_play: parts.strict_play,
+            poll_interval: parts.poll_interval,
+            version: parts.version,
+            last_activity: parts.last_activity,
+            auto_disconnect_timeout: parts.auto_disconnect_timeout,
+            retry_policy: parts.retry_policy,
+            restore_text_edit_mode: parts.restore_text_edit_mode,
+            stall_timeout: parts.stall_timeout,
+            content_limits: parts.content_limits,
+            host_executable: parts.host_executable,
+            presets_reloaded: Rc::new(RefCell::new(Vec::new())),
+            preset_cache: parts.preset_cache,
+            session_id: parts.session_id,
         }
     }
 
+    unsafe fn create_control(name: Option<&str>) -> Result<ITtsControl> {
+        let control: ITtsControl = eventlog::report(
+            "new",
+            CoCreateInstance(&TtsControl, None, CLSCTX_INPROC_SERVER),
+        )
+        .map_err(AiVoiceError::ControlCreate)?;
+
+        let host_names = eventlog::report("new", control.GetAvailableHostNames())?;
+        let available = read_bstr_safearray(host_names)?;
+
+        let host_name = match name {
+            Some(name) => host::find_host_name(&available, name).map_err(|candidates| {
+                AiVoiceError::HostNotFound {
+                    name: name.to_string(),
+                    candidates,
+                }
+            })?,
+            None => available.first().ok_or(AiVoiceError::NoHostAvailable)?,
+        };
+        let host_name = BSTR::from(host_name.as_str());
+
+        eventlog::report(
+            "new",
+            com_call!("initialize", control.Initialize(&host_name)),
+        )?;
+
+        Ok(control)
+    }
+
     /// APIが初期化されているかどうかを取得します。
     ///
+    /// # 注意
+    /// 一度初期化済みであることを確認した後は、ホストプログラムへの問い合わせを省略します。
+    ///
     pub fn is_initialized(&self) -> Result<bool> {
-        Ok(unsafe { self.control.IsInitialized() }?.as_bool())
+        if self.initialized.load(Ordering::Acquire) {
+            return Ok(true);
+        }
+
+        let initialized =
+            com_call!("is_initialized", unsafe { self.control().IsInitialized() })?.as_bool();
+        if initialized {
+            self.initialized.store(true, Ordering::Release);
+        }
+
+        Ok(initialized)
+    }
+
+    fn require_initialized(&self) -> Result<()> {
+        if !self.is_initialized()? {
+            return Err(AiVoiceError::NotInitialized);
+        }
+
+        Ok(())
+    }
+
+    /// `com_call!` で呼び出した COM メソッドの結果を、ホストプログラムの状態に応じたエラーに変換します。
+    pub(crate) fn check_connection<T>(&self, result: windows::core::Result<T>) -> Result<T> {
+        result.map_err(|e| self.connection_error(e))
+    }
+
+    /// COM の呼び出しに失敗した原因がホストプログラムの状態にある場合は、対応する `AiVoiceError` に変換します。
+    ///
+    /// - ホストプログラムの終了を示す HRESULT の場合は `AiVoiceError::HostNotRunning`
+    /// - ホストプログラムが呼び出しを拒否した場合は `AiVoiceError::HostModalBlocking`
+    /// - それ以外の場合は状態を取得し、`NotRunning` なら `AiVoiceError::HostNotRunning`、
+    ///   `NotConnected` なら `AiVoiceError::NotConnected`
+    ///
+    /// 状態を取得できない場合や、接続済みの場合は元のエラーをそのまま返します。
+    fn connection_error(&self, e: windows::core::Error) -> AiVoiceError {
+        if error::is_host_gone(&e) {
+            return AiVoiceError::HostNotRunning;
+        }
+        if error::is_modal_blocking(&e) {
+            return AiVoiceError::HostModalBlocking(e);
+        }
+
+        // `status` はこのメソッドを経由するため、状態は直接取得する
+        let status =
+            unsafe { self.control().Status() }.map(|status| HostStatus::from_raw(status.0));
+        match status {
+            Ok(Some(HostStatus::NotRunning)) => AiVoiceError::HostNotRunning,
+            Ok(Some(HostStatus::NotConnected)) => AiVoiceError::NotConnected,
+            _ => e.into(),
+        }
     }
 
     /// ホストプログラムを起動します。
     ///
+    /// # 注意
+    /// ホストプログラムが既に起動している（状態が `NotRunning` 以外の）場合は何もしません。
+    /// 起動済みのホストプログラムに対する起動要求の動作はホストプログラムのバージョンによって
+    /// 異なる可能性があるため、状態を確認せずに起動する場合は `force_start_host` を使用してください。
+    ///
     pub fn start_host(&self) -> Result<()> {
-        Ok(unsafe { self.control.StartHost() }?)
+        if self.status()? != HostStatus::NotRunning {
+            return Ok(());
+        }
+
+        self.force_start_host()
+    }
+
+    /// ホストプログラムの状態に関わらず、ホストプログラムの起動を要求します。
+    ///
+    /// # 注意
+    /// COM 経由の起動に失敗し、`AiVoiceBuilder::host_executable` が設定されている場合は、
+    /// その実行ファイルを直接起動し、起動完了まで（最大30秒）待機してから
+    /// `AiVoiceBuilder::retry_policy` の方針で接続を試行します。
+    /// この場合、このメソッドの終了時点でホストプログラムと接続済みになります。
+    ///
+    pub fn force_start_host(&self) -> Result<()> {
+        self.require_initialized()?;
+        self.forget_last_text();
+        self.forget_version();
+
+        let result = eventlog::report(
+            &self.log_name("start_host"),
+            com_call!("start_host", unsafe { self.control().StartHost() }),
+        );
+
+        match (result, &self.host_executable) {
+            (Ok(()), _) => Ok(()),
+            (Err(_), Some(host_executable)) => self.start_host_executable(host_executable),
+            (Err(e), None) => Err(e.into()),
+        }
+    }
+
+    /// ホストプログラムの実行ファイルを直接起動し、起動完了を待ってから接続します。
+    fn start_host_executable(&self, host_executable: &Path) -> Result<()> {
+        Command::new(host_executable)
+            .spawn()
+            .map_err(|source| AiVoiceError::HostLaunch {
+                path: host_executable.to_path_buf(),
+                source,
+            })?;
+
+        self.wait_for_start(Instant::now() + Duration::from_secs(30))?;
+        self.retry_policy.run(|| self.connect())
+    }
+
+    /// ホストプログラムを起動し、接続可能な状態になるまで待機します。
+    ///
+    /// # 引数
+    /// * `timeout` - 待機する最大時間
+    ///
+    /// # 注意
+    /// 起動中のホストプログラムの状態は `NotRunning` のまま推移し、
+    /// 起動が完了すると `NotConnected` に変化します。
+    /// このメソッドは状態が `NotRunning` 以外になった時点で終了します。
+    /// 状態は `AiVoiceBuilder::poll_interval` で設定した間隔で取得されます。
+    ///
+    /// # エラー
+    /// `timeout` 以内にホストプログラムが起動しなかった場合にエラーを返します。
+    ///
+    pub fn start_host_and_wait(&self, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+
+        self.start_host()?;
+        self.wait_for_start(deadline)
+    }
+
+    /// ホストプログラムの状態が `NotRunning` 以外になるまで待機します。
+    fn wait_for_start(&self, deadline: Instant) -> Result<()> {
+        let mut previous = self.status()?;
+        while previous == HostStatus::NotRunning {
+            if Instant::now() >= deadline {
+                return Err(AiVoiceError::HostStartTimeout);
+            }
+
+            thread::sleep(self.poll_interval);
+
+            let current = self.status()?;
+            check_transition("start_host_and_wait", previous, current);
+            previous = current;
+        }
+
+        Ok(())
     }
 
     /// ホストプログラムを終了します。
@@ -163,23 +788,134 @@ impl AiVoice {
     /// ホストプログラム上で確認メッセージが表示されます。
     ///
     pub fn terminate_host(&self) -> Result<()> {
-        Ok(unsafe { self.control.TerminateHost() }?)
+        self.require_initialized()?;
+        self.forget_version();
+
+        Ok(com_call!("terminate_host", unsafe {
+            self.control().TerminateHost()
+        })?)
+    }
+
+    /// このインスタンスのセッション ID を取得します。
+    ///
+    /// セッション ID はプロセス内でインスタンスを生成するごとに異なる値が割り当てられ、
+    /// 複製したインスタンスは同じ値を共有します。
+    /// 複数のインスタンスを使用する場合に、ログの出力元を区別するために使用できます。
+    /// `eventlog` 機能で記録されるイベントにも含まれます。
+    ///
+    pub fn session_id(&self) -> u64 {
+        self.session_id
+    }
+
+    /// ログに記録するメソッド名にセッション ID を付加します。
+    fn log_name(&self, method: &str) -> String {
+        format!("{} (session {})", method, self.session_id)
+    }
+
+    /// API の呼び出し時刻を記録して `ITtsControl` を取得します。
+    fn control(&self) -> &ITtsControl {
+        self.last_activity
+            .store(epoch_millis(SystemTime::now()), Ordering::Relaxed);
+        &self.inner.control
+    }
+
+    /// 最後に API を呼び出した時刻を取得します。
+    ///
+    /// # 注意
+    /// 複製したインスタンスは呼び出し時刻を共有するため、
+    /// いずれかのインスタンスで API を呼び出すとすべてのインスタンスに反映されます。
+    ///
+    pub fn last_activity(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.last_activity.load(Ordering::Relaxed))
+    }
+
+    /// 最後に API を呼び出してからの経過時間を取得します。
+    ///
+    /// # 注意
+    /// システム時刻が巻き戻った場合は `Duration::ZERO` を返します。
+    ///
+    pub fn time_since_last_activity(&self) -> Duration {
+        self.last_activity().elapsed().unwrap_or(Duration::ZERO)
+    }
+
+    /// 操作が行われないことによって自動的に接続が解除されるまでの残り時間を取得します。
+    ///
+    /// # 戻り値
+    /// 最後に API を呼び出した時刻から `AiVoiceBuilder::auto_disconnect_timeout` が経過するまでの時間。
+    /// 既に経過している場合は `Duration::ZERO`
+    ///
+    /// # 注意
+    /// このクレートが記録した呼び出し時刻に基づく推定値です。
+    /// ホストプログラム側の計時とは多少ずれることがあります。
+    /// また、他のプロセスからの操作は考慮されません。
+    ///
+    pub fn time_until_auto_disconnect(&self) -> Duration {
+        self.auto_disconnect_timeout
+            .saturating_sub(self.time_since_last_activity())
     }
 
     /// ホストプログラムと接続します。
     ///
+    /// # エラー
+    /// ホストプログラムが `RPC_E_CALL_REJECTED` または `RPC_E_SERVERCALL_RETRYLATER` で
+    /// 呼び出しを拒否した場合に `AiVoiceError::HostModalBlocking` を返します。
+    /// これらは COM のメッセージフィルターが呼び出しを受け付けないときの HRESULT であり、
+    /// ホストプログラムが更新の確認などのダイアログを表示している場合に返されることが多いため、
+    /// 利用者にダイアログを閉じるよう案内してください。
+    /// この判定は推定であり、他の理由で拒否された場合も同じエラーになります。
+    ///
     /// # 注意
     /// ホストプログラムへ接続後、10分間 API を介した操作が行われない状態が続くと
     /// 自動的に接続が解除されます。
     ///
     pub fn connect(&self) -> Result<()> {
-        Ok(unsafe { self.control.Connect() }?)
+        self.require_initialized()?;
+        self.forget_last_text();
+        self.forget_version();
+
+        eventlog::report(
+            &self.log_name("connect"),
+            com_call!("connect", unsafe { self.control().Connect() }),
+        )
+        .map_err(|e| {
+            if error::is_modal_blocking(&e) {
+                AiVoiceError::HostModalBlocking(e)
+            } else {
+                AiVoiceError::Com(e)
+            }
+        })?;
+
+        self.ever_connected.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// このインスタンスが一度でもホストプログラムとの接続に成功したかどうかを取得します。
+    ///
+    /// # 注意
+    /// 複製したインスタンスは状態を共有します。
+    /// 状態が `NotConnected` の場合に、初回の接続前か、
+    /// 自動的な接続解除などによって切断された後かを区別するために使用できます。
+    ///
+    pub fn has_ever_connected(&self) -> bool {
+        self.ever_connected.load(Ordering::Relaxed)
     }
 
     /// ホストプログラムとの接続を解除します。
     ///
+    /// # 注意
+    /// ホストプログラムが既に終了している場合は、接続を解除する対象がないため成功として扱います。
+    /// 次の HRESULT がこれに該当します。
+    /// `RPC_S_SERVER_UNAVAILABLE`, `RPC_S_CALL_FAILED`, `RPC_E_DISCONNECTED`,
+    /// `RPC_E_SERVER_DIED`, `RPC_E_SERVER_DIED_DNE`, `CO_E_OBJNOTCONNECTED`
+    ///
     pub fn disconnect(&self) -> Result<()> {
-        Ok(unsafe { self.control.Disconnect() }?)
+        self.require_initialized()?;
+        self.forget_last_text();
+
+        match com_call!("disconnect", unsafe { self.control().Disconnect() }) {
+            Err(e) if !error::is_host_gone(&e) => Err(e.into()),
+            _ => Ok(()),
+        }
     }
 
     /// ホストプログラムのバージョンを取得します。
@@ -188,7 +924,7 @@ impl AiVoice {
     /// ホストプログラムのバージョン文字列
     ///
     pub fn version(&self) -> Result<String> {
-        Ok(unsafe { self.control.Version() }?.to_string())
+        Ok(com_call!(self, "version", unsafe { self.control().Version() })?.to_string())
     }
 
     /// ホストプログラムの状態を取得します。
@@ -196,16 +932,21 @@ impl AiVoice {
     /// # 戻り値
     /// `HostStatus` 列挙型で表されるホストプログラムの状態
     ///
+    /// # エラー
+    /// ホストプログラムの終了を示す RPC のエラーが返された場合は `AiVoiceError::HostNotRunning` を、
+    /// 未知の状態の値が返された場合は `AiVoiceError::UnknownStatus` を返します。
+    ///
     pub fn status(&self) -> Result<HostStatus> {
-        let host_status = unsafe { self.control.Status() }?;
-
-        match host_status {
-            ai_voice_sys::HostStatus(0) => Ok(HostStatus::NotRunning),
-            ai_voice_sys::HostStatus(1) => Ok(HostStatus::NotConnected),
-            ai_voice_sys::HostStatus(2) => Ok(HostStatus::Idle),
-            ai_voice_sys::HostStatus(3) => Ok(HostStatus::Busy),
-            _ => anyhow::bail!("Unknown host status"),
-        }
+        self.require_initialized()?;
+
+        let host_status = com_call!("status", unsafe { self.control().Status() }).map_err(|e| {
+            if error::is_host_gone(&e) {
+                AiVoiceError::HostNotRunning
+            } else {
+                AiVoiceError::Com(e)
+            }
+        })?;
+        host_status_from_sys(host_status)
     }
 
     /// マスターコントロールの現在の設定を取得します。
@@ -214,8 +955,34 @@ impl AiVoice {
     /// `MasterControl` 構造体で表されるマスターコントロールの設定
     ///
     pub fn master_control(&self) -> Result<MasterControl> {
-        let master_control = unsafe { self.control.MasterControl() }?.to_string();
-        serde_json::from_str(&master_control).with_context(|| "Failed to parse master control")
+        let master_control = com_call!(self, "master_control", unsafe {
+            self.control().MasterControl()
+        })?
+        .to_string();
+        Ok(serde_json::from_str(&master_control)?)
+    }
+
+    /// マスターコントロールの設定を寛容に解析して取得します。
+    ///
+    /// ホストプログラムが不完全な JSON を返した場合でも、認識できた項目から
+    /// `MasterControl` を組み立てて返します。
+    ///
+    /// # 戻り値
+    /// 解析結果。JSON を厳密に解析できなかった場合、取得できなかった項目には
+    /// 標準値（ボリューム・話速・高さ・抑揚は1.0、短ポーズは150、長ポーズは370、文末ポーズは800）が使用され、
+    /// `LenientParse::missing_fields` にその項目名が含まれます。
+    ///
+    /// # 注意
+    /// 各項目は `"Volume":` のような項目名を文字列として検索して取り出すため、
+    /// 厳密な解析に失敗した場合の値はあくまで推定です。
+    /// 常に正しい値が必要な場合は `master_control` を使用してください。
+    ///
+    pub fn master_control_lenient(&self) -> Result<LenientParse<MasterControl>> {
+        let master_control = com_call!(self, "master_control", unsafe {
+            self.control().MasterControl()
+        })?
+        .to_string();
+        Ok(lenient::parse_master_control(&master_control))
     }
 
     /// マスターコントロールの設定を適用します。
@@ -226,18 +993,15 @@ impl AiVoice {
     /// * `master_control` - 適用する `MasterControl` 構造体
     ///
     pub fn apply_master_control(&self, master_control: &MasterControl) -> Result<()> {
-        let master_control = MasterControl {
-            volume: master_control.volume.clamp(0.0, 5.0),
-            speed: master_control.speed.clamp(0.0, 4.0),
-            pitch: master_control.pitch.clamp(0.0, 2.0),
-            pitch_range: master_control.pitch_range.clamp(0.0, 2.0),
-            middle_pause: master_control.middle_pause.clamp(0, 500),
-            long_pause: master_control.long_pause.clamp(0, 2000),
-            sentence_pause: master_control.sentence_pause.clamp(0, 10000),
-        };
+        self.invalidate_play_time();
+
+        let mut master_control = master_control.clone();
+        master_control.clamp();
 
         let master_control = serde_json::to_string(&master_control)?;
-        Ok(unsafe { self.control.SetMasterControl(&BSTR::from(master_control)) }?)
+        Ok(com_call!(self, "apply_master_control", unsafe {
+            self.control().SetMasterControl(&BSTR::from(master_control))
+        })?)
     }
 
     /// テキスト形式の入力テキストを取得します。
@@ -246,7 +1010,14 @@ impl AiVoice {
     /// 現在設定されているテキスト
     ///
     pub fn text(&self) -> Result<String> {
-        Ok(unsafe { self.control.Text() }?.to_string())
+        let text = com_call!(self, "text", unsafe { self.control().Text() })?.to_string();
+
+        let mut last_text = self.last_text.lock().unwrap();
+        if last_text.as_deref() != Some(text.as_str()) {
+            *last_text = None;
+        }
+
+        Ok(text)
     }
 
     /// テキスト形式の入力テキストを設定します。
@@ -254,8 +1025,76 @@ impl AiVoice {
     /// # 引数
     /// * `value` - 設定するテキスト
     ///
+    /// # エラー
+    /// `AiVoiceBuilder::check_content_size` が有効で、`value` の長さが
+    /// `AiVoiceBuilder::content_limits` で設定した上限を超える場合に
+    /// `AiVoiceError::ContentTooLarge` を返します。
+    ///
+    /// # 注意
+    /// 直前にこのインスタンス（およびその複製）から設定したテキストと同じ場合、
+    /// ホストプログラムは呼び出されません。
+    /// `text` で取得したテキストが直前に設定したテキストと異なる場合
+    /// （ホストプログラム上で編集された場合など）、この記録は破棄されます。
+    /// ホストプログラムのテキストが別の経路で変わりうる `connect` / `disconnect` /
+    /// `start_host` / `force_start_host` / `set_text_edit_mode` の呼び出し時にも破棄されます。
+    /// 常にホストプログラムを呼び出す場合は `set_text_force` を使用してください。
+    ///
     pub fn set_text(&self, value: &str) -> Result<()> {
-        Ok(unsafe { self.control.SetText(&BSTR::from(value)) }?)
+        if self.last_text.lock().unwrap().as_deref() == Some(value) {
+            return Ok(());
+        }
+
+        self.set_text_force(value)
+    }
+
+    /// 直前に設定したテキストに関わらず、テキスト形式の入力テキストを設定します。
+    ///
+    /// # 引数
+    /// * `value` - 設定するテキスト
+    ///
+    /// # エラー
+    /// `set_text` と同じ条件で `AiVoiceError::ContentTooLarge` を返します。
+    ///
+    pub fn set_text_force(&self, value: &str) -> Result<()> {
+        if let Some(limits) = &self.content_limits {
+            check_text_len(value, limits)?;
+        }
+
+        self.invalidate_play_time();
+
+        let mut last_text = self.last_text.lock().unwrap();
+        *last_text = None;
+
+        com_call!(self, "set_text", unsafe {
+            self.control().SetText(&BSTR::from(value))
+        })?;
+
+        *last_text = Some(value.to_string());
+        Ok(())
+    }
+
+    /// 直前に設定したテキストの記録を破棄します。
+    fn forget_last_text(&self) {
+        *self.last_text.lock().unwrap() = None;
+    }
+
+    /// キャッシュしたホストプログラムのバージョンを破棄します。
+    fn forget_version(&self) {
+        *self.version.lock().unwrap() = None;
+    }
+
+    /// テキスト形式の入力テキストを設定し、選択位置をテキストの末尾に移動します。
+    ///
+    /// # 引数
+    /// * `value` - 設定するテキスト
+    ///
+    /// # 注意
+    /// 選択位置はホストプログラムと同じく UTF-16 のコード単位で計算されます。
+    ///
+    pub fn set_text_and_select_end(&self, value: &str) -> Result<()> {
+        self.set_text(value)?;
+        self.set_text_selection_start(utf16_len(value))?;
+        self.set_text_selection_length(0)
     }
 
     /// テキスト形式の入力テキストの選択開始位置を取得します。
@@ -264,7 +1103,9 @@ impl AiVoice {
     /// 選択開始位置（0から始まるインデックス）
     ///
     pub fn text_selection_start(&self) -> Result<i32> {
-        Ok(unsafe { self.control.TextSelectionStart() }?)
+        Ok(com_call!(self, "text_selection_start", unsafe {
+            self.control().TextSelectionStart()
+        })?)
     }
 
     /// テキスト形式の入力テキストの選択開始位置を設定します。
@@ -273,7 +1114,9 @@ impl AiVoice {
     /// * `value` - 設定する選択開始位置（0から始まるインデックス）
     ///
     pub fn set_text_selection_start(&self, value: i32) -> Result<()> {
-        Ok(unsafe { self.control.SetTextSelectionStart(value) }?)
+        Ok(com_call!(self, "set_text_selection_start", unsafe {
+            self.control().SetTextSelectionStart(value)
+        })?)
     }
 
     /// テキスト形式の入力テキストの選択文字数を取得します。
@@ -282,7 +1125,9 @@ impl AiVoice {
     /// 選択されているテキストの文字数
     ///
     pub fn text_selection_length(&self) -> Result<i32> {
-        Ok(unsafe { self.control.TextSelectionLength() }?)
+        Ok(com_call!(self, "text_selection_length", unsafe {
+            self.control().TextSelectionLength()
+        })?)
     }
 
     /// テキスト形式の入力テキストの選択文字数を設定します。
@@ -291,7 +1136,9 @@ impl AiVoice {
     /// * `value` - 設定する選択文字数
     ///
     pub fn set_text_selection_length(&self, value: i32) -> Result<()> {
-        Ok(unsafe { self.control.SetTextSelectionLength(value) }?)
+        Ok(com_call!(self, "set_text_selection_length", unsafe {
+            self.control().SetTextSelectionLength(value)
+        })?)
     }
 
     /// 現在のテキスト編集モードを取得します。
@@ -300,13 +1147,12 @@ impl AiVoice {
     /// `TextEditMode` 列挙型で表されるテキスト編集モード
     ///
     pub fn text_edit_mode(&self) -> Result<TextEditMode> {
-        let text_edit_mode = unsafe { self.control.TextEditMode() }?;
+        let text_edit_mode = com_call!(self, "text_edit_mode", unsafe {
+            self.control().TextEditMode()
+        })?;
 
-        match text_edit_mode {
-            ai_voice_sys::TextEditMode(0) => Ok(TextEditMode::Text),
-            ai_voice_sys::TextEditMode(1) => Ok(TextEditMode::List),
-            _ => anyhow::bail!("Unknown text edit mode"),
-        }
+        TextEditMode::from_raw(text_edit_mode.0)
+            .ok_or_else(|| AiVoiceError::UnknownTextEditMode(text_edit_mode.0))
     }
 
     /// テキスト編集モードを設定します。
@@ -315,30 +1161,367 @@ impl AiVoice {
     /// * `mode` - 設定する `TextEditMode` 列挙型のテキスト編集モード
     ///
     pub fn set_text_edit_mode(&self, mode: TextEditMode) -> Result<()> {
-        let text_edit_mode = match mode {
-            TextEditMode::Text => ai_voice_sys::TextEditMode(0),
-            TextEditMode::List => ai_voice_sys::TextEditMode(1),
-        };
+        self.invalidate_play_time();
+        self.forget_last_text();
 
-        Ok(unsafe { self.control.SetTextEditMode(text_edit_mode) }?)
+        let text_edit_mode = ai_voice_sys::TextEditMode(mode.to_raw());
+
+        Ok(com_call!(self, "set_text_edit_mode", unsafe {
+            self.control().SetTextEditMode(text_edit_mode)
+        })?)
     }
 
-    /// 音声の再生を開始または一時停止します。
+    /// テキスト編集モードを設定し、実際に切り替わったことを確認します。
+    ///
+    /// # 引数
+    /// * `mode` - 設定する `TextEditMode` 列挙型のテキスト編集モード
+    ///
+    /// # エラー
+    /// 設定後に取得したテキスト編集モードが `mode` と異なる場合に `AiVoiceError::ModeSwitchFailed` を返します。
     ///
     /// # 注意
-    /// - ホストプログラムで選択されているテキスト入力形式で再生が行われます。
+    /// ホストプログラムは再生中や音声の保存中など、処理中（`Busy`）の状態では
+    /// テキスト編集モードの切り替えを無視することがあります。
+    /// `set_text_edit_mode` はこの場合もエラーを返しません。
+    ///
+    pub fn set_text_edit_mode_verified(&self, mode: TextEditMode) -> Result<()> {
+        self.set_text_edit_mode(mode)?;
+
+        let actual = self.text_edit_mode()?;
+        if actual != mode {
+            return Err(AiVoiceError::ModeSwitchFailed {
+                requested: mode,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// 音声の再生を開始または一時停止します。
+    ///
+    /// # 注意
+    /// - ホストプログラムで選択されているテキスト入力形式で再生が行われます。
     /// - 再生の開始時、このメソッドは再生を開始すると終了し、再生の完了を待ちません。
     /// - ホストプログラムでフレーズが編集状態の場合、編集内容は破棄されます。
     /// - ホストプログラムで単語が編集状態の場合、その編集内容は読み上げに反映されません。
+    /// - `AiVoiceBuilder::strict_play` が有効な場合、`play_strict` と同様に動作します。
     ///
     pub fn play(&self) -> Result<()> {
-        Ok(unsafe { self.control.Play() }?)
+        if self.strict_play {
+            return self.play_strict();
+        }
+
+        Ok(com_call!(self, "play", unsafe { self.control().Play() })?)
+    }
+
+    /// 音声の再生を開始し、再生が終了する予定時刻を返します。
+    ///
+    /// # 戻り値
+    /// `play_time` で取得した再生時間を現在時刻に加えた時刻
+    ///
+    /// # 注意
+    /// 戻り値は推定値です。ホストプログラムが再生を開始するまでの遅延は含まれません。
+    ///
+    pub fn play_until(&self) -> Result<Instant> {
+        let play_time = Duration::from_millis(self.play_time()?.max(0) as u64);
+        self.play()?;
+        Ok(Instant::now() + play_time)
+    }
+
+    /// 再生する内容が存在することを確認してから、音声の再生を開始または一時停止します。
+    ///
+    /// # エラー
+    /// テキスト形式でテキストが空の場合、またはリスト形式で行が存在しない場合に
+    /// `AiVoiceError::NothingToPlay` を返します。
+    ///
+    pub fn play_strict(&self) -> Result<()> {
+        if self.content_is_empty()? {
+            return Err(AiVoiceError::NothingToPlay);
+        }
+
+        Ok(com_call!(self, "play", unsafe { self.control().Play() })?)
+    }
+
+    /// テキスト形式に切り替え、指定されたテキストを再生します。
+    ///
+    /// # 引数
+    /// * `text` - 再生するテキスト
+    ///
+    /// # エラー
+    /// `text` が空の場合に `AiVoiceError::NothingToPlay` を返します。
+    ///
+    /// # 注意
+    /// `AiVoiceBuilder::restore_text_edit_mode` が有効な場合、再生の完了を待ってから
+    /// 元のテキスト入力形式に戻します。無効な場合は再生の開始後すぐに終了し、
+    /// テキスト入力形式はテキスト形式のままになります。
+    ///
+    pub fn play_text(&self, text: &str) -> Result<()> {
+        if text.is_empty() {
+            return Err(AiVoiceError::NothingToPlay);
+        }
+
+        self.play_in_mode(TextEditMode::Text, || self.set_text(text))
+    }
+
+    /// リスト形式に切り替え、リスト形式の内容を再生します。
+    ///
+    /// # エラー
+    /// リスト形式に行が存在しない場合に `AiVoiceError::NothingToPlay` を返します。
+    ///
+    /// # 注意
+    /// `AiVoiceBuilder::restore_text_edit_mode` が有効な場合、再生の完了を待ってから
+    /// 元のテキスト入力形式に戻します。無効な場合は再生の開始後すぐに終了し、
+    /// テキスト入力形式はリスト形式のままになります。
+    ///
+    pub fn play_list(&self) -> Result<()> {
+        self.play_in_mode(TextEditMode::List, || {
+            if self.list_count()? == 0 {
+                return Err(AiVoiceError::NothingToPlay);
+            }
+            Ok(())
+        })
+    }
+
+    fn play_in_mode(&self, mode: TextEditMode, prepare: impl FnOnce() -> Result<()>) -> Result<()> {
+        let previous = self.text_edit_mode()?;
+        self.set_text_edit_mode_verified(mode)?;
+
+        let result = prepare().and_then(|_| {
+            let deadline = self.play_until()?;
+            if self.restore_text_edit_mode && previous != mode {
+                self.wait_for_playback(deadline, None)?;
+            }
+            Ok(())
+        });
+
+        let restored = if self.restore_text_edit_mode && previous != mode {
+            self.set_text_edit_mode(previous)
+        } else {
+            Ok(())
+        };
+
+        // 両方が失敗した場合は、再生のエラーを優先して返す
+        result.and(restored)
+    }
+
+    /// 音声の再生を開始し、再生の完了を待ちます。
+    ///
+    /// # エラー
+    /// `AiVoiceBuilder::stall_timeout` が設定されている場合、推定終了時刻をその猶予以上過ぎても
+    /// ホストプログラムが処理中のままのときに `AiVoiceError::PlaybackStalled` を返します。
+    ///
+    /// # 注意
+    /// 再生する内容が空の場合など、ホストプログラムが処理中にならないときは、
+    /// `play_time` から推定した終了時刻を過ぎた時点で終了します。
+    ///
+    pub fn play_and_wait(&self) -> Result<()> {
+        let deadline = self.play_until()?;
+        self.wait_for_playback(deadline, None)
+    }
+
+    /// 音声の再生を開始し、最大 `timeout` まで再生の完了を待ちます。
+    ///
+    /// # 引数
+    /// * `timeout` - 待機する最大時間
+    ///
+    /// # エラー
+    /// `timeout` 以内に再生が完了しなかった場合に `AiVoiceError::PlaybackTimeout` を返します。
+    /// そのほか `play_and_wait` と同じ条件でエラーを返します。
+    ///
+    /// # 注意
+    /// タイムアウトしても再生は停止しません。停止する場合は `stop` を呼び出してください。
+    ///
+    pub fn play_and_wait_timeout(&self, timeout: Duration) -> Result<()> {
+        let timeout_at = Instant::now() + timeout;
+        let deadline = self.play_until()?;
+        self.wait_for_playback(deadline, Some((timeout_at, timeout)))
+    }
+
+    /// ホストプログラムが処理中でなくなるまで待機します。
+    ///
+    /// # エラー
+    /// `AiVoiceBuilder::stall_timeout` が設定されている場合、呼び出し時点の `play_time` から推定した
+    /// 再生の終了時刻をその猶予以上過ぎても処理中のままのときに `AiVoiceError::PlaybackStalled` を返します。
+    ///
+    /// # 注意
+    /// - 再生の開始直後に呼び出すと、ホストプログラムが処理中になる前に終了することがあります。
+    ///   再生を開始してから完了まで待つ場合は `play_and_wait` を使用してください。
+    /// - `ITtsControl` は接続ポイントなどのイベント通知や、完了まで待機する API を公開していないため、
+    ///   常に `AiVoiceBuilder::poll_interval` の間隔で `status` を取得して完了を検出します。
+    ///   そのため、完了の検出には最大でこの間隔分の遅れが生じます。
+    ///
+    pub fn wait_for_idle(&self) -> Result<()> {
+        let expected_end = Instant::now() + Duration::from_millis(self.play_time()?.max(0) as u64);
+        let mut watchdog = StallWatchdog::new(expected_end, self.stall_timeout);
+
+        while self.status()? == HostStatus::Busy {
+            watchdog.observe_busy(Instant::now())?;
+            thread::sleep(self.poll_interval);
+        }
+
+        Ok(())
+    }
+
+    /// 再生の完了を待ちます。完了の判定は `PlaybackWaiter` で行います。
+    ///
+    /// `timeout` を指定した場合、その時刻を過ぎると `AiVoiceError::PlaybackTimeout` を返します。
+    ///
+    fn wait_for_playback(
+        &self,
+        deadline: Instant,
+        timeout: Option<(Instant, Duration)>,
+    ) -> Result<()> {
+        let mut waiter = PlaybackWaiter::new(deadline, self.stall_timeout);
+
+        loop {
+            if waiter.step(self.status()?, Instant::now())? {
+                return Ok(());
+            }
+
+            if let Some((timeout_at, timeout)) = timeout {
+                if Instant::now() >= timeout_at {
+                    return Err(AiVoiceError::PlaybackTimeout { timeout });
+                }
+            }
+
+            thread::sleep(self.poll_interval);
+        }
+    }
+
+    /// 現在のテキスト入力形式で再生する内容が存在しないかどうかを取得します。
+    ///
+    /// # 戻り値
+    /// テキスト形式ではテキストが空の場合、リスト形式では行が存在しない場合に `true`
+    ///
+    pub fn content_is_empty(&self) -> Result<bool> {
+        Ok(match self.text_edit_mode()? {
+            TextEditMode::Text => self.text()?.is_empty(),
+            TextEditMode::List => self.list_count()? == 0,
+        })
+    }
+
+    /// 現在のテキスト入力形式で再生する内容のフィンガープリントを取得します。
+    ///
+    /// 前回取得した値と比較することで、再生内容に関わる変更の有無を検出できます。
+    /// フィンガープリントには次の内容が含まれます。
+    /// - テキスト形式: テキスト入力形式、テキスト、現在のボイスプリセット名
+    /// - リスト形式: テキスト入力形式、行数、選択行のインデックス
+    ///
+    /// # 戻り値
+    /// 再生内容のハッシュ値
+    ///
+    /// # 注意
+    /// - ホストプログラムへの問い合わせは3回で、リスト形式でも行数に依存しません。
+    /// - リスト形式では各行の内容は含まれないため、行数を変えずに行のテキストや
+    ///   ボイスプリセットを変更した場合は検出されません。
+    ///   行の内容も比較する場合は `content_fingerprint_with_rows` を使用してください。
+    /// - ボイスプリセットの各パラメータやマスターコントロールの値は含まれません。
+    /// - ハッシュ値は同じプロセス内での比較にのみ使用してください。
+    ///   Rust のバージョンなどによって値が変わる可能性があるため、永続化には適しません。
+    ///
+    pub fn content_fingerprint(&self) -> Result<u64> {
+        self.fingerprint(false)
+    }
+
+    /// リスト形式では全行の内容も含めて、再生内容のフィンガープリントを取得します。
+    ///
+    /// テキスト形式では `content_fingerprint` と同じ値を返します。
+    /// リスト形式では `content_fingerprint` の内容に加えて、全行のボイスプリセット名とテキスト
+    /// （行の順序を含む）が含まれます。
+    ///
+    /// # 戻り値
+    /// 再生内容のハッシュ値
+    ///
+    /// # 注意
+    /// リスト形式では `list_items` で全行を取得するため、行数に比例してホストプログラムへの問い合わせが増え、
+    /// 処理中は選択状態が変化します。頻繁に呼び出す処理には `content_fingerprint` を使用してください。
+    ///
+    pub fn content_fingerprint_with_rows(&self) -> Result<u64> {
+        self.fingerprint(true)
+    }
+
+    fn fingerprint(&self, include_rows: bool) -> Result<u64> {
+        let mut hasher = DefaultHasher::new();
+
+        match self.text_edit_mode()? {
+            TextEditMode::Text => {
+                0u8.hash(&mut hasher);
+                self.text()?.hash(&mut hasher);
+                self.current_voice_preset_name()?.hash(&mut hasher);
+            }
+            TextEditMode::List => {
+                1u8.hash(&mut hasher);
+                self.list_count()?.hash(&mut hasher);
+                self.list_selection_indices()?.hash(&mut hasher);
+                if include_rows {
+                    self.list_items()?.hash(&mut hasher);
+                }
+            }
+        }
+
+        Ok(hasher.finish())
     }
 
     /// 音声の再生を停止します。
     ///
     pub fn stop(&self) -> Result<()> {
-        Ok(unsafe { self.control.Stop() }?)
+        Ok(com_call!(self, "stop", unsafe { self.control().Stop() })?)
+    }
+
+    /// 再生中の場合のみ音声の再生を停止します。
+    ///
+    /// # 注意
+    /// ホストプログラムの状態が `Busy` でない場合は何もしません。
+    ///
+    pub fn ensure_stopped(&self) -> Result<()> {
+        if self.status()? == HostStatus::Busy {
+            self.stop()?;
+        }
+
+        Ok(())
+    }
+
+    /// 音声の再生を停止し、次の `play` が先頭から再生されるように再生位置を戻します。
+    ///
+    /// `ITtsControl` は再生位置を戻す API を公開していないため、停止後に次のように再設定します。
+    /// - テキスト形式: 現在のテキストを設定し直し、選択開始位置を0、選択文字数を0にします。
+    /// - リスト形式: 現在の選択行を選択し直します。
+    ///
+    /// # 注意
+    /// - テキスト形式では選択範囲が失われます。
+    /// - ホストプログラム上で編集中のフレーズや単語の編集状態は、テキストの再設定によって破棄される場合があります。
+    ///
+    pub fn reset(&self) -> Result<()> {
+        self.stop()?;
+
+        match self.text_edit_mode()? {
+            TextEditMode::Text => {
+                let text = self.text()?;
+                self.set_text_force(&text)?;
+                self.set_text_selection_start(0)?;
+                self.set_text_selection_length(0)
+            }
+            TextEditMode::List => {
+                let selection = self.list_selection_indices()?;
+                self.set_list_selection_indices(selection)
+            }
+        }
+    }
+
+    /// 再生中でない場合のみ音声の再生を開始します。
+    ///
+    /// # 注意
+    /// `play` は再生中に呼び出すと一時停止として動作しますが、
+    /// このメソッドはホストプログラムの状態が `Busy` の場合は何もしないため、
+    /// 再生中の音声が一時停止されることはありません。
+    ///
+    pub fn ensure_playing(&self) -> Result<()> {
+        if self.status()? != HostStatus::Busy {
+            self.play()?;
+        }
+
+        Ok(())
     }
 
     /// テキストの読み上げ音声を指定されたファイルに保存します。
@@ -353,7 +1536,47 @@ impl AiVoice {
     /// - ホストプログラムでフレーズや単語が編集状態の場合、その編集内容は読み上げに反映されません。
     ///
     pub fn save_audio_to_file(&self, path: &str) -> Result<()> {
-        Ok(unsafe { self.control.SaveAudioToFile(&BSTR::from(path)) }?)
+        Ok(com_call!(self, "save_audio_to_file", unsafe {
+            self.control().SaveAudioToFile(&BSTR::from(path))
+        })?)
+    }
+
+    /// 出力先ファイルが既に存在する場合の動作を指定して、テキストの読み上げ音声を保存します。
+    ///
+    /// # 引数
+    /// * `path` - 出力先ファイルパス
+    /// * `policy` - 出力先ファイルが既に存在する場合の動作
+    ///
+    /// # 戻り値
+    /// ホストプログラムに指定した出力先ファイルパス
+    ///
+    /// # 注意
+    /// - `Skip` と `Rename` の判定はホストプログラムを呼び出す前に行われます。
+    /// - `Rename` の場合、`name.wav` に対して `name_1.wav`、`name_2.wav` のように
+    ///   存在しないパスが見つかるまで連番を増やします。
+    /// - ホストプログラムによって拡張子が付加された場合、そのパスは判定の対象になりません。
+    ///
+    /// # エラー
+    /// `Skip` で出力先ファイルが既に存在する場合に `AiVoiceError::FileExists` を返します。
+    ///
+    pub fn save_audio_to_file_opts(
+        &self,
+        path: impl AsRef<Path>,
+        policy: OverwritePolicy,
+    ) -> Result<PathBuf> {
+        let path = path.as_ref();
+
+        let path = match policy {
+            OverwritePolicy::Overwrite => path.to_path_buf(),
+            OverwritePolicy::Skip if path.exists() => {
+                return Err(AiVoiceError::FileExists(path.to_path_buf()))
+            }
+            OverwritePolicy::Skip => path.to_path_buf(),
+            OverwritePolicy::Rename => unique_path(path),
+        };
+
+        self.save_audio_to_file(&path.to_string_lossy())?;
+        Ok(path)
     }
 
     /// 読み上げ音声の再生時間を取得します。
@@ -365,9 +1588,62 @@ impl AiVoice {
     /// - ホストプログラムで選択されているテキスト入力形式の再生時間を取得します。
     /// - ホストプログラムでフレーズや単語が編集状態の場合、その編集内容は
     ///   再生時間に反映されません。
+    /// - テキスト形式では、このインスタンス（およびその複製）から `set_text` / `set_text_force` で
+    ///   設定したテキストごとに再生時間をキャッシュし、同じテキストに対しては
+    ///   `GetPlayTime` を呼び出さずにキャッシュした値を返します。
+    ///   テキストを設定していない場合や、リスト形式の場合はキャッシュを使用しません。
+    /// - ホストプログラム上で直接行われたテキストやテキスト入力形式の変更は、`text` の呼び出しで
+    ///   差異が見つかるまで検出されないため、必ず最新の値を取得する場合は
+    ///   `play_time_uncached` を使用してください。
+    ///
+    /// キャッシュは次の場合に破棄されます。
+    /// - `set_text` / `set_text_force` でテキストを設定したとき
+    /// - `text` で取得したテキストが直前に設定したテキストと異なるとき
+    /// - `set_text_edit_mode` でテキスト入力形式を変更したとき
+    /// - `connect` / `disconnect` / `start_host` / `force_start_host` を呼び出したとき
+    /// - `set_list_*` / `add_list_item` / `add_list_items` / `insert_list_item` / `remove_list_item` /
+    ///   `clear_list_items` でリスト形式の選択や行を変更したとき
+    /// - `set_current_voice_preset_name` / `set_voice_preset` / `apply_master_control` で
+    ///   ボイスプリセットやマスターコントロールを変更したとき
+    /// - `reload_*` で辞書やボイスプリセットを再読込みしたとき
     ///
     pub fn play_time(&self) -> Result<i64> {
-        Ok(unsafe { self.control.GetPlayTime() }?)
+        let Some(key) = self.last_text.lock().unwrap().as_deref().map(|text| {
+            let mut hasher = DefaultHasher::new();
+            text.hash(&mut hasher);
+            hasher.finish()
+        }) else {
+            return self.play_time_uncached();
+        };
+
+        if let Some((cached_key, play_time)) = *self.play_time.lock().unwrap() {
+            if key == cached_key {
+                return Ok(play_time);
+            }
+        }
+
+        if self.text_edit_mode()? != TextEditMode::Text {
+            return self.play_time_uncached();
+        }
+
+        let play_time = self.play_time_uncached()?;
+        *self.play_time.lock().unwrap() = Some((key, play_time));
+        Ok(play_time)
+    }
+
+    /// キャッシュを使用せずに、ホストプログラムから再生時間を取得します。
+    ///
+    /// # 戻り値
+    /// 再生時間（ミリ秒）
+    ///
+    pub fn play_time_uncached(&self) -> Result<i64> {
+        Ok(com_call!(self, "play_time", unsafe {
+            self.control().GetPlayTime()
+        })?)
+    }
+
+    fn invalidate_play_time(&self) {
+        *self.play_time.lock().unwrap() = None;
     }
 
     /// リスト形式の行数を取得します。
@@ -376,7 +1652,9 @@ impl AiVoice {
     /// リスト形式の行数
     ///
     pub fn list_count(&self) -> Result<i32> {
-        Ok(unsafe { self.control.GetListCount() }?)
+        Ok(com_call!(self, "list_count", unsafe {
+            self.control().GetListCount()
+        })?)
     }
 
     /// リスト形式で選択されている行のインデックスを取得します。
@@ -385,23 +1663,11 @@ impl AiVoice {
     /// 選択行のインデックスのベクター（0スタート）
     ///
     pub fn list_selection_indices(&self) -> Result<Vec<i32>> {
-        let indices = unsafe { self.control.GetListSelectionIndices() }?;
-
-        let lob = unsafe { SafeArrayGetLBound(indices, 1) }?;
-        let upb = unsafe { SafeArrayGetUBound(indices, 1) }?;
+        let indices = com_call!(self, "list_selection_indices", unsafe {
+            self.control().GetListSelectionIndices()
+        })?;
 
-        let mut selection_indices = Vec::with_capacity((lob.abs() + upb.abs() + 1) as usize);
-        for i in lob..upb + 1 {
-            let data = unsafe {
-                let mut result__ = i32::default();
-                SafeArrayGetElement(indices, &i, &mut result__ as *mut i32 as *mut _)?;
-                result__
-            };
-
-            selection_indices.push(data);
-        }
-
-        Ok(selection_indices)
+        unsafe { read_safearray(indices, |index: i32| index) }
     }
 
     /// リスト形式の選択行数を取得します。
@@ -410,7 +1676,9 @@ impl AiVoice {
     /// リスト形式の選択行数
     ///
     pub fn list_selection_count(&self) -> Result<i32> {
-        Ok(unsafe { self.control.GetListSelectionCount() }?)
+        Ok(com_call!(self, "list_selection_count", unsafe {
+            self.control().GetListSelectionCount()
+        })?)
     }
 
     /// リスト形式の単一行を選択状態にします。
@@ -422,7 +1690,11 @@ impl AiVoice {
     /// 存在しないインデックスの指定は無視されます。
     ///
     pub fn set_list_selection_index(&self, index: i32) -> Result<()> {
-        Ok(unsafe { self.control.SetListSelectionIndex(index) }?)
+        self.invalidate_play_time();
+
+        Ok(com_call!(self, "set_list_selection_index", unsafe {
+            self.control().SetListSelectionIndex(index)
+        })?)
     }
 
     /// リスト形式の任意の複数行を選択状態にします。
@@ -433,26 +1705,71 @@ impl AiVoice {
     /// # 注意
     /// 存在しないインデックスの指定は無視されます。
     ///
-    pub fn set_list_selection_indices(&self, indices: Vec<String>) -> Result<()> {
-        let rgsabound = vec![SAFEARRAYBOUND {
+    pub fn set_list_selection_indices(&self, indices: Vec<i32>) -> Result<()> {
+        self.invalidate_play_time();
+
+        let bound = SAFEARRAYBOUND {
             cElements: indices.len() as u32,
             lLbound: 0,
-        }];
-        let psa = unsafe { SafeArrayCreate(VT_BSTR, 0, rgsabound.as_ptr()) };
+        };
 
-        indices
-            .iter()
-            .enumerate()
-            .map(|(i, elem)| unsafe {
-                SafeArrayPutElement(
-                    psa,
-                    &(i as i32),
-                    std::mem::transmute_copy(&BSTR::from(elem)),
-                )
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+        unsafe {
+            let psa = SafeArrayCreate(VT_I4, 1, &bound);
+            if psa.is_null() {
+                return Err(AiVoiceError::Com(E_OUTOFMEMORY.into()));
+            }
+
+            let result = indices
+                .iter()
+                .enumerate()
+                .try_for_each(|(i, index)| {
+                    SafeArrayPutElement(psa, &(i as i32), index as *const i32 as *const c_void)
+                })
+                .and_then(|_| {
+                    com_call!(
+                        "set_list_selection_indices",
+                        self.control().SetListSelectionIndices(psa)
+                    )
+                });
+
+            SafeArrayDestroy(psa)?;
+            Ok(result?)
+        }
+    }
+
+    /// リスト形式の指定行を現在の選択状態に追加します。
+    ///
+    /// # 引数
+    /// * `index` - 選択状態に追加する行のインデックス（0スタート）
+    ///
+    /// # 注意
+    /// 既に選択されている行を指定した場合、選択状態は変わりません。
+    ///
+    pub fn add_to_list_selection(&self, index: i32) -> Result<()> {
+        self.check_list_index(index)?;
+
+        let mut indices = self.list_selection_indices()?;
+        if !indices.contains(&index) {
+            indices.push(index);
+        }
+
+        self.set_list_selection_indices(indices)
+    }
+
+    /// リスト形式の指定行を現在の選択状態から除外します。
+    ///
+    /// # 引数
+    /// * `index` - 選択状態から除外する行のインデックス（0スタート）
+    ///
+    pub fn remove_from_list_selection(&self, index: i32) -> Result<()> {
+        self.check_list_index(index)?;
 
-        Ok(unsafe { self.control.SetListSelectionIndices(psa) }?)
+        let indices = self.list_selection_indices()?;
+        self.set_list_selection_indices(indices.into_iter().filter(|&i| i != index).collect())
+    }
+
+    fn check_list_index(&self, index: i32) -> Result<()> {
+        check_list_range(index, 1, self.list_count()?)
     }
 
     /// リスト形式の任意の範囲行を選択状態にします。
@@ -465,7 +1782,26 @@ impl AiVoice {
     /// 存在しないインデックスの指定は無視されます。
     ///
     pub fn set_list_selection_range(&self, startindex: i32, length: i32) -> Result<()> {
-        Ok(unsafe { self.control.SetListSelectionRange(startindex, length) }?)
+        self.invalidate_play_time();
+
+        Ok(com_call!(self, "set_list_selection_range", unsafe {
+            self.control().SetListSelectionRange(startindex, length)
+        })?)
+    }
+
+    /// 範囲を検証してから、リスト形式の任意の範囲行を選択状態にします。
+    ///
+    /// # 引数
+    /// * `startindex` - 選択開始行のインデックス（0スタート）
+    /// * `length` - 選択状態にする行数
+    ///
+    /// # エラー
+    /// `startindex` または `length` が負の場合、
+    /// または範囲がリスト形式の行数を超える場合に `AiVoiceError::IndexOutOfRange` を返します。
+    ///
+    pub fn set_list_selection_range_checked(&self, startindex: i32, length: i32) -> Result<()> {
+        check_list_range(startindex, length, self.list_count()?)?;
+        self.set_list_selection_range(startindex, length)
     }
 
     /// リスト形式の末尾に行を追加します。
@@ -474,11 +1810,22 @@ impl AiVoice {
     /// * `voice_preset_name` - ボイスプリセット名
     /// * `text` - テキスト
     ///
+    /// # エラー
+    /// `AiVoiceBuilder::check_content_size` が有効で、追加後の行数が
+    /// `AiVoiceBuilder::content_limits` で設定した上限を超える場合に `AiVoiceError::ContentTooLarge` を返します。
+    ///
+    /// # 注意
+    /// 上限の確認が有効な場合、確認のために `list_count` を1回呼び出します。
+    /// 複数の行を追加する場合は、確認が1回で済む `add_list_items` を使用してください。
+    ///
     pub fn add_list_item(&self, voice_preset_name: &str, text: &str) -> Result<()> {
-        Ok(unsafe {
-            self.control
+        self.check_list_rows_after_adding(1)?;
+        self.invalidate_play_time();
+
+        Ok(com_call!(self, "add_list_item", unsafe {
+            self.control()
                 .AddListItem(&BSTR::from(voice_preset_name), &BSTR::from(text))
-        }?)
+        })?)
     }
 
     /// リスト形式の選択位置に行を挿入します。
@@ -487,14 +1834,20 @@ impl AiVoice {
     /// * `voice_preset_name` - ボイスプリセット名
     /// * `text` - テキスト
     ///
+    /// # エラー
+    /// `add_list_item` と同じ条件で `AiVoiceError::ContentTooLarge` を返します。
+    ///
     /// # 注意
     /// 単一行が選択されている場合のみ実行可能です。
     ///
     pub fn insert_list_item(&self, voice_preset_name: &str, text: &str) -> Result<()> {
-        Ok(unsafe {
-            self.control
+        self.check_list_rows_after_adding(1)?;
+        self.invalidate_play_time();
+
+        Ok(com_call!(self, "insert_list_item", unsafe {
+            self.control()
                 .InsertListItem(&BSTR::from(voice_preset_name), &BSTR::from(text))
-        }?)
+        })?)
     }
 
     /// リスト形式の選択行を削除します。
@@ -503,13 +1856,96 @@ impl AiVoice {
     /// 選択された複数行に対して実行可能です。
     ///
     pub fn remove_list_item(&self) -> Result<()> {
-        Ok(unsafe { self.control.RemoveListItem() }?)
+        self.invalidate_play_time();
+
+        Ok(com_call!(self, "remove_list_item", unsafe {
+            self.control().RemoveListItem()
+        })?)
+    }
+
+    /// リスト形式の末尾に複数の行を追加します。
+    ///
+    /// # 引数
+    /// * `items` - 追加する行
+    ///
+    /// # エラー
+    /// `AiVoiceBuilder::check_content_size` が有効で、追加後の行数が
+    /// `AiVoiceBuilder::content_limits` で設定した上限を超える場合に `AiVoiceError::ContentTooLarge` を返します。
+    /// この場合、行は追加されません。
+    ///
+    /// # 注意
+    /// - 上限の確認は追加前に1回だけ行われます。
+    /// - 途中で失敗した場合、それまでに追加された行は残ります。
+    ///
+    pub fn add_list_items(&self, items: &[ListItem]) -> Result<()> {
+        self.check_list_rows_after_adding(items.len())?;
+        self.invalidate_play_time();
+
+        self.add_list_rows(items)
+    }
+
+    /// 上限の確認やキャッシュの無効化を行わずに、リスト形式の末尾に行を追加します。
+    fn add_list_rows(&self, items: &[ListItem]) -> Result<()> {
+        for item in items {
+            com_call!(self, "add_list_item", unsafe {
+                self.control().AddListItem(
+                    &BSTR::from(item.voice_preset_name.as_str()),
+                    &BSTR::from(item.text.as_str()),
+                )
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn check_list_rows_after_adding(&self, added: usize) -> Result<()> {
+        if let Some(limits) = &self.content_limits {
+            check_list_rows(self.list_count()?.max(0) as usize + added, limits)?;
+        }
+
+        Ok(())
     }
 
     /// リスト形式の行をすべて削除します。
     ///
     pub fn clear_list_items(&self) -> Result<()> {
-        Ok(unsafe { self.control.ClearListItems() }?)
+        self.invalidate_play_time();
+
+        Ok(com_call!(self, "clear_list_items", unsafe {
+            self.control().ClearListItems()
+        })?)
+    }
+
+    /// リスト形式の行をすべて削除し、`items` で置き換えます。
+    ///
+    /// 行数の上限の確認は行を削除する前に行われます。
+    ///
+    /// # 引数
+    /// * `items` - 置き換える行
+    ///
+    /// # エラー
+    /// `AiVoiceBuilder::check_content_size` が有効で、`items` の行数が
+    /// `AiVoiceBuilder::content_limits` で設定した上限を超える場合に
+    /// `AiVoiceError::ContentTooLarge` を返します。この場合、既存の行は削除されません。
+    ///
+    /// # 注意
+    /// - `ITtsControl` には行の一括追加や、描画更新を一時停止する API（BeginUpdate / EndUpdate に相当するもの）が
+    ///   存在しません。そのため `clear_list_items` の後に `add_list_item` を繰り返し呼び出す場合と同じく、
+    ///   1行ごとに1回の COM 呼び出しが発生し、このメソッドによる高速化はありません。
+    /// - 途中で失敗した場合、それまでに追加された行は残ります。
+    ///
+    pub fn replace_list(&self, items: &[ListItem]) -> Result<()> {
+        if let Some(limits) = &self.content_limits {
+            check_list_rows(items.len(), limits)?;
+        }
+
+        self.invalidate_play_time();
+
+        com_call!(self, "clear_list_items", unsafe {
+            self.control().ClearListItems()
+        })?;
+
+        self.add_list_rows(items)
     }
 
     /// リスト形式の選択行のボイスプリセット名を取得します。
@@ -521,7 +1957,10 @@ impl AiVoice {
     /// 単一行が選択されている場合のみ実行可能です。
     ///
     pub fn list_voice_preset(&self) -> Result<String> {
-        Ok(unsafe { self.control.GetListVoicePreset() }?.to_string())
+        Ok(com_call!(self, "list_voice_preset", unsafe {
+            self.control().GetListVoicePreset()
+        })?
+        .to_string())
     }
 
     /// リスト形式の選択行のボイスプリセット名を設定します。
@@ -533,10 +1972,12 @@ impl AiVoice {
     /// 単一行が選択されている場合のみ実行可能です。
     ///
     pub fn set_list_voice_preset(&self, voice_preset_name: &str) -> Result<()> {
-        Ok(unsafe {
-            self.control
+        self.invalidate_play_time();
+
+        Ok(com_call!(self, "set_list_voice_preset", unsafe {
+            self.control()
                 .SetListVoicePreset(&BSTR::from(voice_preset_name))
-        }?)
+        })?)
     }
 
     /// リスト形式の選択行のセンテンスを取得します。
@@ -548,7 +1989,10 @@ impl AiVoice {
     /// 単一行が選択されている場合のみ実行可能です。
     ///
     pub fn list_sentence(&self) -> Result<String> {
-        Ok(unsafe { self.control.GetListSentence() }?.to_string())
+        Ok(com_call!(self, "list_sentence", unsafe {
+            self.control().GetListSentence()
+        })?
+        .to_string())
     }
 
     /// 利用可能なボイス名を取得します。
@@ -557,23 +2001,61 @@ impl AiVoice {
     /// ボイス名のベクター
     ///
     pub fn voice_names(&self) -> Result<Vec<String>> {
-        let voice_names = unsafe { self.control.VoiceNames() }?;
-
-        let lob = unsafe { SafeArrayGetLBound(voice_names, 1) }?;
-        let upb = unsafe { SafeArrayGetUBound(voice_names, 1) }?;
+        let voice_names = com_call!(self, "voice_names", unsafe { self.control().VoiceNames() })?;
 
-        let mut voice_names_vec = Vec::with_capacity((lob.abs() + upb.abs() + 1) as usize);
-        for i in lob..upb + 1 {
-            let data = unsafe {
-                let mut result__ = BSTR::default();
-                SafeArrayGetElement(voice_names, &i, &mut result__ as *mut BSTR as *mut _)?;
-                result__
-            };
+        unsafe { read_bstr_safearray(voice_names) }
+    }
 
-            voice_names_vec.push(data.to_string());
+    /// 指定されたボイスが利用可能かどうかを調べます。
+    ///
+    /// API はライセンスの有無を公開していないため、このメソッドは
+    /// 指定ボイスを使用するボイスプリセットを一時的に現在のボイスプリセットに設定し、
+    /// 短いテキストの再生時間を取得できるかどうかで判定します。
+    ///
+    /// # 引数
+    /// * `name` - 調べるボイス名
+    ///
+    /// # 戻り値
+    /// ボイスが利用可能な場合は `true`
+    ///
+    /// # 注意
+    /// - 判定のためにテキスト入力形式、テキスト形式の入力テキスト、現在のボイスプリセットを
+    ///   一時的に変更します。判定後はいずれも元の値に戻されます。
+    /// - 指定ボイスを使用するボイスプリセットが存在しない場合は `false` を返します。
+    ///
+    pub fn is_voice_usable(&self, name: &str) -> Result<bool> {
+        let mut probe_preset = None;
+        for preset_name in self.voice_preset_names()? {
+            if self.voice_preset(&preset_name)?.voice_name == name {
+                probe_preset = Some(preset_name);
+                break;
+            }
         }
 
-        Ok(voice_names_vec)
+        let Some(probe_preset) = probe_preset else {
+            return Ok(false);
+        };
+
+        let current_mode = self.text_edit_mode()?;
+        let current_preset = self.current_voice_preset_name()?;
+
+        let usable = self.set_text_edit_mode(TextEditMode::Text).and_then(|_| {
+            let current_text = self.text()?;
+
+            let usable = self
+                .set_current_voice_preset_name(&probe_preset)
+                .and_then(|_| self.set_text("あ"))
+                .and_then(|_| self.play_time_uncached())
+                .is_ok();
+
+            self.set_text(&current_text)?;
+            Ok(usable)
+        });
+
+        self.set_current_voice_preset_name(&current_preset)?;
+        self.set_text_edit_mode(current_mode)?;
+
+        usable
     }
 
     /// 登録されているボイスプリセット名を取得します。
@@ -585,23 +2067,11 @@ impl AiVoice {
     /// 標準ボイスプリセットとユーザーボイスプリセットの両方が含まれます。
     ///
     pub fn voice_preset_names(&self) -> Result<Vec<String>> {
-        let preset_names = unsafe { self.control.VoicePresetNames() }?;
-
-        let lob = unsafe { SafeArrayGetLBound(preset_names, 1) }?;
-        let upb = unsafe { SafeArrayGetUBound(preset_names, 1) }?;
+        let preset_names = com_call!(self, "voice_preset_names", unsafe {
+            self.control().VoicePresetNames()
+        })?;
 
-        let mut preset_names_vec = Vec::with_capacity((lob.abs() + upb.abs() + 1) as usize);
-        for i in lob..upb {
-            let data = unsafe {
-                let mut result__ = BSTR::default();
-                SafeArrayGetElement(preset_names, &i, &mut result__ as *mut BSTR as *mut _)?;
-                result__
-            };
-
-            preset_names_vec.push(data.to_string());
-        }
-
-        Ok(preset_names_vec)
+        unsafe { read_bstr_safearray(preset_names) }
     }
 
     /// 現在のボイスプリセット名を取得します。
@@ -610,7 +2080,10 @@ impl AiVoice {
     /// 現在のボイスプリセット名
     ///
     pub fn current_voice_preset_name(&self) -> Result<String> {
-        Ok(unsafe { self.control.CurrentVoicePresetName() }?.to_string())
+        Ok(com_call!(self, "current_voice_preset_name", unsafe {
+            self.control().CurrentVoicePresetName()
+        })?
+        .to_string())
     }
 
     /// 現在のボイスプリセット名を設定します。
@@ -619,10 +2092,24 @@ impl AiVoice {
     /// * `preset_name` - 設定するボイスプリセット名
     ///
     pub fn set_current_voice_preset_name(&self, preset_name: &str) -> Result<()> {
-        Ok(unsafe {
-            self.control
+        self.invalidate_play_time();
+
+        Ok(com_call!(self, "set_current_voice_preset_name", unsafe {
+            self.control()
                 .SetCurrentVoicePresetName(&BSTR::from(preset_name))
-        }?)
+        })?)
+    }
+
+    /// 指定された名前のボイスプリセットが存在するかどうかを取得します。
+    ///
+    /// # 引数
+    /// * `preset_name` - ボイスプリセット名
+    ///
+    pub fn preset_exists(&self, preset_name: &str) -> Result<bool> {
+        Ok(self
+            .voice_preset_names()?
+            .iter()
+            .any(|name| name == preset_name))
     }
 
     /// 指定されたボイスプリセットの情報を取得します。
@@ -636,18 +2123,95 @@ impl AiVoice {
     /// # エラー
     /// ボイスプリセットの解析に失敗した場合にエラーを返します。
     ///
+    /// # 注意
+    /// - 比較や差分の取得で結果が安定するよう、スタイル情報は
+    ///   `StyleName` の順（喜び、怒り、悲しみ）に並べ替えられます。
+    /// - 解析結果はボイスプリセット名ごとにキャッシュされ（最大32件）、
+    ///   `set_voice_preset` / `add_voice_preset` / `reload_voice_presets` を呼び出すと破棄されます。
+    ///   ホストプログラム上で直接ボイスプリセットが編集された場合、
+    ///   次にキャッシュが破棄されるまで古い内容が返されることがあります。
+    ///   常に最新の内容を取得する場合は `voice_preset_uncached` を使用してください。
+    /// - 複製したインスタンスはキャッシュを共有します。
+    ///
     pub fn voice_preset(&self, preset_name: &str) -> Result<VoicePreset> {
-        let voice_preset =
-            unsafe { self.control.GetVoicePreset(&BSTR::from(preset_name)) }?.to_string();
-        serde_json::from_str(&voice_preset).with_context(|| "Failed to parse voice preset")
+        if let Some(voice_preset) = self.preset_cache.lock().unwrap().get(preset_name) {
+            return Ok(voice_preset);
+        }
+
+        let voice_preset = self.voice_preset_uncached(preset_name)?;
+        self.preset_cache
+            .lock()
+            .unwrap()
+            .insert(voice_preset.clone());
+        Ok(voice_preset)
+    }
+
+    /// キャッシュを使用せずに、ボイスプリセットを取得します。
+    ///
+    /// # 引数
+    /// * `preset_name` - 取得するボイスプリセットの名前
+    ///
+    /// # 戻り値
+    /// `VoicePreset`構造体で表されるボイスプリセットの情報
+    ///
+    /// # 注意
+    /// 取得した結果でキャッシュは更新されません。
+    ///
+    pub fn voice_preset_uncached(&self, preset_name: &str) -> Result<VoicePreset> {
+        let voice_preset = com_call!(self, "voice_preset", unsafe {
+            self.control().GetVoicePreset(&BSTR::from(preset_name))
+        })?
+        .to_string();
+        let mut voice_preset = VoicePreset::from_json(&voice_preset)?;
+        voice_preset.sort_styles();
+        Ok(voice_preset)
     }
+
+    /// ボイスプリセットを寛容に解析して取得します。
+    ///
+    /// ホストプログラムが不完全な JSON を返した場合でも、認識できた項目から
+    /// `VoicePreset` を組み立てて返します。キャッシュは使用しません。
+    ///
+    /// # 引数
+    /// * `preset_name` - ボイスプリセット名
+    ///
+    /// # 戻り値
+    /// 解析結果。JSON を厳密に解析できなかった場合、取得できなかった項目には
+    /// `VoicePreset::from_voice` と同じ値が使用され、`LenientParse::missing_fields` にその項目名が含まれます。
+    ///
+    /// # エラー
+    /// ボイス名を取得できない場合にエラーを返します。
+    ///
+    /// # 注意
+    /// 厳密な解析に失敗した場合、数値の項目とボイスプリセット名は項目名の文字列検索で、
+    /// スタイル情報とフュージョン情報は対応する括弧までの部分の解析で取得する推定値です。
+    /// 結果をそのまま `set_voice_preset` で書き込むと、取得できなかった項目は既定値で上書きされます。
+    ///
+    pub fn voice_preset_lenient(&self, preset_name: &str) -> Result<LenientParse<VoicePreset>> {
+        let voice_preset = com_call!(self, "voice_preset", unsafe {
+            self.control().GetVoicePreset(&BSTR::from(preset_name))
+        })?
+        .to_string();
+        let mut parsed = lenient::parse_voice_preset(&voice_preset)?;
+        parsed.value.sort_styles();
+        Ok(parsed)
+    }
+
     /// 既存のボイスプリセットに指定された設定を適用します。
     ///
     /// # 引数
     /// * `voice_preset` - 適用する`VoicePreset`構造体
     pub fn set_voice_preset(&self, voice_preset: &VoicePreset) -> Result<()> {
-        let json = serde_json::to_string(voice_preset)?;
-        Ok(unsafe { self.control.SetVoicePreset(&BSTR::from(json)) }?)
+        self.invalidate_play_time();
+        self.invalidate_preset_cache();
+
+        let json = voice_preset.to_json_compact()?;
+        com_call!(self, "set_voice_preset", unsafe {
+            self.control().SetVoicePreset(&BSTR::from(json))
+        })?;
+
+        self.notify_presets_reloaded();
+        Ok(())
     }
 
     /// 新規ボイスプリセットを作成します。
@@ -655,32 +2219,398 @@ impl AiVoice {
     /// # 引数
     /// * `voice_preset` - 作成する`VoicePreset`構造体
     ///
+    /// # エラー
+    /// 同じ名前のボイスプリセットが既に存在する場合に `AiVoiceError::PresetAlreadyExists` を返します。
+    /// 確認を省略してホストプログラムに作成を要求する場合は `add_voice_preset_force` を使用してください。
+    ///
     pub fn add_voice_preset(&self, voice_preset: &VoicePreset) -> Result<()> {
-        let json = serde_json::to_string(voice_preset)?;
-        Ok(unsafe { self.control.AddVoicePreset(&BSTR::from(json)) }?)
+        if self.preset_exists(&voice_preset.preset_name)? {
+            return Err(AiVoiceError::PresetAlreadyExists(
+                voice_preset.preset_name.clone(),
+            ));
+        }
+
+        self.add_voice_preset_force(voice_preset)
+    }
+
+    /// 同じ名前のボイスプリセットが存在するかを確認せずに、新規ボイスプリセットを作成します。
+    ///
+    /// # 引数
+    /// * `voice_preset` - 作成する`VoicePreset`構造体
+    ///
+    /// # 注意
+    /// 同じ名前のボイスプリセットが既に存在する場合の動作はホストプログラムに依存します。
+    ///
+    pub fn add_voice_preset_force(&self, voice_preset: &VoicePreset) -> Result<()> {
+        self.invalidate_preset_cache();
+
+        let json = voice_preset.to_json_compact()?;
+        com_call!(self, "add_voice_preset", unsafe {
+            self.control().AddVoicePreset(&BSTR::from(json))
+        })?;
+
+        self.notify_presets_reloaded();
+        Ok(())
     }
 
     /// ボイスプリセットを再読込みします。
     ///
     pub fn reload_voice_presets(&self) -> Result<()> {
-        Ok(unsafe { self.control.ReloadVoicePresets() }?)
+        self.invalidate_play_time();
+        self.invalidate_preset_cache();
+
+        com_call!(self, "reload_voice_presets", unsafe {
+            self.control().ReloadVoicePresets()
+        })?;
+
+        self.notify_presets_reloaded();
+        Ok(())
+    }
+
+    /// ボイスプリセットが変更されたときに呼び出されるコールバックを登録します。
+    ///
+    /// # 引数
+    /// * `callback` - 呼び出されるコールバック
+    ///
+    /// # 注意
+    /// - コールバックは `reload_voice_presets` / `add_voice_preset` / `set_voice_preset` の
+    ///   COM 呼び出しが成功した後、呼び出し元のスレッドで同期的に呼び出されます。
+    ///   失敗した場合は呼び出されません。
+    /// - 複製したインスタンスはコールバックを共有します。
+    /// - ホストプログラム上で直接行われた変更では呼び出されません。
+    /// - コールバックは登録したインスタンスとその複製がすべて破棄されるまで保持されます。
+    ///   コールバックが `AiVoice` の複製を保持すると循環参照となり、
+    ///   `ITtsControl` の解放や `CoUninitialize` が行われなくなります。
+    ///   この場合は、不要になった時点で `clear_callbacks` を呼び出してください。
+    ///
+    pub fn on_presets_reloaded(&self, callback: impl Fn() + 'static) {
+        self.presets_reloaded.borrow_mut().push(Rc::new(callback));
+    }
+
+    /// `on_presets_reloaded` で登録したコールバックをすべて削除します。
+    ///
+    /// # 注意
+    /// 複製したインスタンスで登録したコールバックも削除されます。
+    ///
+    pub fn clear_callbacks(&self) {
+        self.presets_reloaded.borrow_mut().clear();
+    }
+
+    fn invalidate_preset_cache(&self) {
+        self.preset_cache.lock().unwrap().clear();
+    }
+
+    fn notify_presets_reloaded(&self) {
+        // コールバック内から登録できるよう、借用を解放してから呼び出す
+        let callbacks = self.presets_reloaded.borrow().clone();
+        for callback in callbacks {
+            callback();
+        }
     }
 
     /// フレーズ辞書を再読込みします。
     ///
     pub fn reload_phrase_dictionary(&self) -> Result<()> {
-        Ok(unsafe { self.control.ReloadPhraseDictionary() }?)
+        self.invalidate_play_time();
+
+        Ok(com_call!(self, "reload_phrase_dictionary", unsafe {
+            self.control().ReloadPhraseDictionary()
+        })?)
     }
 
     /// 単語辞書を再読込みします。
     ///
     pub fn reload_word_dictionary(&self) -> Result<()> {
-        Ok(unsafe { self.control.ReloadWordDictionary() }?)
+        self.invalidate_play_time();
+
+        Ok(com_call!(self, "reload_word_dictionary", unsafe {
+            self.control().ReloadWordDictionary()
+        })?)
+    }
+
+    /// 単語辞書を再読込みし、`word` の読み上げ時間の変化から再読込みが反映されたかを推定します。
+    ///
+    /// 再読込みの前後で `word` をテキストに設定して `play_time` を取得し、両者を比較します。
+    /// 辞書の変更で `word` の読みが変わっていれば、通常は再生時間も変化します。
+    ///
+    /// # 引数
+    /// * `word` - 変更した単語辞書の見出し語など、変更が反映されると読みが変わる文字列
+    ///
+    /// # 戻り値
+    /// 再読込みの前後で再生時間が変化した場合は `true`
+    ///
+    /// # エラー
+    /// `word` が空文字列の場合に `AiVoiceError::NothingToPlay` を返します。
+    ///
+    /// # 注意
+    /// - 経験的な判定です。読みが変わっても再生時間が同じ場合は `false` を返し、
+    ///   辞書以外の要因で再生時間が変わった場合は `true` を返すことがあります。
+    /// - テキスト入力形式をテキスト形式に切り替え、テキストを `word` で上書きします。
+    ///   元のテキスト入力形式やテキストには戻しません。
+    ///
+    pub fn reload_word_dictionary_probe(&self, word: &str) -> Result<bool> {
+        if word.is_empty() {
+            return Err(AiVoiceError::NothingToPlay);
+        }
+
+        self.set_text_edit_mode(TextEditMode::Text)?;
+        self.set_text_force(word)?;
+        let before = self.play_time_uncached()?;
+
+        self.reload_word_dictionary()?;
+
+        self.set_text_force(word)?;
+        let after = self.play_time_uncached()?;
+
+        Ok(before != after)
     }
 
     /// 記号ポーズ辞書を再読込みします。
     ///
     pub fn reload_symbol_dictionary(&self) -> Result<()> {
-        Ok(unsafe { self.control.ReloadSymbolDictionary() }?)
+        self.invalidate_play_time();
+
+        Ok(com_call!(self, "reload_symbol_dictionary", unsafe {
+            self.control().ReloadSymbolDictionary()
+        })?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 下限が `lower_bound` の `BSTR` の SafeArray を作成して読み取ります。
+    fn read_bstrs(names: &[&str], lower_bound: i32) -> Result<Vec<String>> {
+        unsafe {
+            let bound = SAFEARRAYBOUND {
+                cElements: names.len() as u32,
+                lLbound: lower_bound,
+            };
+            let psa = SafeArrayCreate(VT_BSTR, 1, &bound);
+            for (i, name) in names.iter().enumerate() {
+                let name = BSTR::from(*name);
+                SafeArrayPutElement(psa, &(lower_bound + i as i32), name.as_ptr() as *const _)?;
+            }
+
+            let result = read_bstr_safearray(psa);
+            SafeArrayDestroy(psa)?;
+            result
+        }
+    }
+
+    #[test]
+    fn read_bstr_safearray_includes_upper_bound() -> Result<()> {
+        let names = ["琴葉 茜", "琴葉 葵", "結月 ゆかり"];
+        assert_eq!(read_bstrs(&names, 0)?, names);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_safearray_handles_empty_and_single() -> Result<()> {
+        assert!(read_bstrs(&[], 0)?.is_empty());
+        assert_eq!(read_bstrs(&["琴葉 茜"], 0)?, ["琴葉 茜"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_safearray_honors_lower_bound() -> Result<()> {
+        assert_eq!(read_bstrs(&["a", "b"], 1)?, ["a", "b"]);
+
+        unsafe {
+            let bound = SAFEARRAYBOUND {
+                cElements: 3,
+                lLbound: 5,
+            };
+            let psa = SafeArrayCreate(VT_I4, 1, &bound);
+            for (i, value) in [10i32, 20, 30].iter().enumerate() {
+                SafeArrayPutElement(psa, &(5 + i as i32), value as *const i32 as *const _)?;
+            }
+
+            let result = read_safearray(psa, |value: i32| value);
+            SafeArrayDestroy(psa)?;
+            assert_eq!(result?, [10, 20, 30]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn host_status_raw_round_trip() {
+        for (raw, status) in [
+            (0, HostStatus::NotRunning),
+            (1, HostStatus::NotConnected),
+            (2, HostStatus::Idle),
+            (3, HostStatus::Busy),
+        ] {
+            assert_eq!(HostStatus::from_raw(raw), Some(status));
+            assert_eq!(status.to_raw(), raw);
+        }
+        assert_eq!(HostStatus::from_raw(-1), None);
+        assert_eq!(HostStatus::from_raw(4), None);
+    }
+
+    #[test]
+    fn text_edit_mode_reports_not_connected() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.disconnect()?;
+
+        let e = ai_voice.text_edit_mode().unwrap_err();
+        assert!(matches!(&e, AiVoiceError::NotConnected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn uninitialized_control_reports_not_initialized() -> Result<()> {
+        let control: ITtsControl = unsafe {
+            CoInitializeEx(None, COINIT_MULTITHREADED).ok()?;
+            CoCreateInstance(&TtsControl, None, CLSCTX_INPROC_SERVER)?
+        };
+        let ai_voice = AiVoice::from_control(control, true);
+
+        assert!(!ai_voice.is_initialized()?);
+        let e = ai_voice.status().unwrap_err();
+        assert!(matches!(&e, AiVoiceError::NotInitialized));
+
+        Ok(())
+    }
+
+    #[test]
+    fn com_failures_report_not_connected_after_disconnect() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        ai_voice.disconnect()?;
+
+        let is_not_connected = |e: AiVoiceError| matches!(e, AiVoiceError::NotConnected);
+        assert!(is_not_connected(ai_voice.text().unwrap_err()));
+        assert!(is_not_connected(
+            ai_voice.set_text_force("こんにちは").unwrap_err()
+        ));
+        assert!(is_not_connected(ai_voice.play_time_uncached().unwrap_err()));
+        assert!(is_not_connected(ai_voice.list_count().unwrap_err()));
+        assert!(is_not_connected(ai_voice.voice_preset_names().unwrap_err()));
+        assert!(is_not_connected(ai_voice.master_control().unwrap_err()));
+        assert_eq!(ai_voice.status()?, HostStatus::NotConnected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_host_status_is_typed() {
+        let e = host_status_from_sys(ai_voice_sys::HostStatus(7)).unwrap_err();
+        assert!(matches!(&e, AiVoiceError::UnknownStatus(7)));
+    }
+
+    #[test]
+    fn host_status_transition_matrix() {
+        use HostStatus::*;
+
+        let statuses = [NotRunning, NotConnected, Idle, Busy];
+        let expected = [
+            // NotRunning, NotConnected, Idle, Busy
+            [true, true, false, false], // NotRunning
+            [true, true, true, false],  // NotConnected
+            [true, true, true, true],   // Idle
+            [true, true, true, true],   // Busy
+        ];
+
+        for (from, row) in statuses.iter().zip(expected) {
+            for (to, allowed) in statuses.iter().zip(row) {
+                assert_eq!(
+                    from.can_transition_to(to),
+                    allowed,
+                    "{:?} -> {:?}",
+                    from,
+                    to
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn text_edit_mode_raw_round_trip() {
+        for (raw, mode) in [(0, TextEditMode::Text), (1, TextEditMode::List)] {
+            assert_eq!(TextEditMode::from_raw(raw), Some(mode));
+            assert_eq!(mode.to_raw(), raw);
+        }
+        assert_eq!(TextEditMode::from_raw(2), None);
+    }
+
+    #[test]
+    fn clear_callbacks_breaks_reference_cycle() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+
+        let cloned = ai_voice.clone();
+        ai_voice.on_presets_reloaded(move || {
+            let _ = &cloned;
+        });
+        assert_eq!(Rc::strong_count(&ai_voice.inner), 2);
+
+        ai_voice.clear_callbacks();
+        assert_eq!(Rc::strong_count(&ai_voice.inner), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn last_text_is_forgotten_on_mode_change_and_connect() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        ai_voice.set_text_edit_mode(TextEditMode::Text)?;
+
+        ai_voice.set_text("こんにちは")?;
+        assert!(ai_voice.last_text.lock().unwrap().is_some());
+        ai_voice.set_text_edit_mode(TextEditMode::Text)?;
+        assert!(ai_voice.last_text.lock().unwrap().is_none());
+
+        ai_voice.set_text("こんにちは")?;
+        ai_voice.connect()?;
+        assert!(ai_voice.last_text.lock().unwrap().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_voice_usable_probes_in_text_mode_and_restores_list_mode() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        ai_voice.set_text_edit_mode(TextEditMode::List)?;
+
+        let voice_name = ai_voice
+            .voice_preset(&ai_voice.current_voice_preset_name()?)?
+            .voice_name;
+        assert!(ai_voice.is_voice_usable(&voice_name)?);
+        assert_eq!(ai_voice.text_edit_mode()?, TextEditMode::List);
+
+        Ok(())
+    }
+
+    #[test]
+    fn play_time_is_cached_per_text_and_not_in_list_mode() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        ai_voice.set_text_edit_mode(TextEditMode::Text)?;
+
+        ai_voice.set_text("こんにちは")?;
+        let play_time = ai_voice.play_time()?;
+        assert!(matches!(
+            *ai_voice.play_time.lock().unwrap(),
+            Some((_, cached)) if cached == play_time
+        ));
+
+        ai_voice.set_text_edit_mode(TextEditMode::List)?;
+        ai_voice.set_text("こんにちは")?;
+        ai_voice.play_time()?;
+        assert!(ai_voice.play_time.lock().unwrap().is_none());
+
+        Ok(())
     }
 }
@@ -0,0 +1,50 @@
+//! `tokio` ランタイム上でホストプログラムの起動・接続を待機する機能
+
+use crate::{AiVoice, Result};
+
+impl AiVoice {
+    /// 非同期ランタイムを停止させずに、ホストプログラムと接続済みの `AiVoice` を生成します。
+    ///
+    /// `ready` の非同期版です。
+    /// 呼び出し元のスレッドで生成した `AiVoice` を `SharedAiVoice` として
+    /// `tokio::task::spawn_blocking` のスレッドに渡し、時間のかかるホストプログラムの起動と
+    /// 起動完了の待機、接続の再試行をそのスレッドで行います。
+    /// 接続状態は両方のスレッドで共有されるため、戻り値のインスタンスは待機の完了時点で接続済みです。
+    ///
+    /// # エラー
+    /// `ready` と同じ条件でエラーを返します。
+    ///
+    /// # 注意
+    /// - `AiVoice` は `Send` ではないため、この Future も `Send` ではありません。
+    /// - 戻り値を `.await` をまたいで保持する Future は `Send` にならないため、
+    ///   `tokio::task::LocalSet` や `current_thread` ランタイム上で使用してください。
+    ///
+    pub async fn ready_async() -> Result<Self> {
+        let ai_voice = Self::new()?;
+        let shared = ai_voice.share()?;
+
+        tokio::task::spawn_blocking(move || shared.get()?.ensure_ready())
+            .await
+            // 待機中のタスクがパニックした場合は、呼び出し元にパニックを伝播する
+            .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))?;
+
+        Ok(ai_voice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HostStatus;
+
+    #[tokio::test]
+    async fn ready_async_returns_connected_instance() -> Result<()> {
+        let ai_voice = AiVoice::ready_async().await?;
+        assert!(matches!(
+            ai_voice.status()?,
+            HostStatus::Idle | HostStatus::Busy
+        ));
+
+        Ok(())
+    }
+}
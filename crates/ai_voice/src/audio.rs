@@ -0,0 +1,335 @@
+use std::{
+    ffi::OsStr,
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{AiVoice, AiVoiceError, HostStatus, Result, TextEditMode};
+
+/// ディレクトリ内で `since` 以降に更新された最新のファイルを探します。
+fn newest_file_since(dir: &Path, since: SystemTime) -> Result<Option<PathBuf>> {
+    let mut newest: Option<(SystemTime, PathBuf)> = None;
+
+    for entry in fs::read_dir(dir).map_err(AiVoiceError::file(dir))? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if !metadata.is_file() {
+            continue;
+        }
+
+        let modified = metadata.modified()?;
+        if modified < since {
+            continue;
+        }
+
+        match &newest {
+            Some((newest_modified, _)) if *newest_modified >= modified => {}
+            _ => newest = Some((modified, entry.path())),
+        }
+    }
+
+    Ok(newest.map(|(_, path)| path))
+}
+
+/// ファイル名が `prefix` で始まるかどうかを判定します。
+///
+/// 非 UTF-8 のファイル名でも誤判定しないように、`OsStr` のまま比較します。
+fn has_prefix(name: &OsStr, prefix: &OsStr) -> bool {
+    name.as_encoded_bytes()
+        .starts_with(prefix.as_encoded_bytes())
+}
+
+/// `path`、またはホストプログラムが拡張子を付加した `path` のうち、空でないファイルを探します。
+fn non_empty_saved_file(path: &Path) -> Option<PathBuf> {
+    let is_non_empty = |path: &Path| fs::metadata(path).is_ok_and(|m| m.is_file() && m.len() > 0);
+
+    if is_non_empty(path) {
+        return Some(path.to_path_buf());
+    }
+
+    let file_name = path.file_name()?;
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .find(|candidate| {
+            candidate
+                .file_name()
+                .is_some_and(|name| has_prefix(name, file_name))
+                && is_non_empty(candidate)
+        })
+}
+
+/// 破棄時に削除される一時ファイル
+struct TempFile(PathBuf);
+
+impl TempFile {
+    /// 一時ディレクトリ内に、他と重複しない一時ファイルのパスを作成します。
+    ///
+    /// ファイル自体は作成されません。
+    fn new(extension: &str) -> Result<Self> {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        Ok(TempFile(std::env::temp_dir().join(format!(
+            "ai_voice_{}_{}.{}",
+            std::process::id(),
+            millis,
+            extension
+        ))))
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+impl AiVoice {
+    /// テキストの読み上げ音声を一時ファイルに保存し、その内容を `sink` に書き込みます。
+    ///
+    /// # 引数
+    /// * `sink` - 音声データの書き込み先
+    ///
+    /// # 戻り値
+    /// `sink` に書き込んだバイト数
+    ///
+    /// # 注意
+    /// - ホストプログラムはファイルへの保存のみに対応しているため、一時ディレクトリに保存してから読み込みます。
+    ///   一時ファイルは書き込みの成否に関わらず削除されます。
+    /// - 保存の完了は `save_audio_to_file_wait` と同様に待機し、待機する最大時間は
+    ///   `play_time` で取得した再生時間に30秒を加えた時間です。
+    /// - 音声の形式はホストプログラムの音声保存の設定に従います。
+    ///
+    pub fn write_audio(&self, mut sink: impl io::Write) -> Result<u64> {
+        let temp = TempFile::new("wav")?;
+        let timeout =
+            Duration::from_millis(self.play_time()?.max(0) as u64) + Duration::from_secs(30);
+
+        let saved = self.save_audio_to_file_wait(&temp.0, timeout)?;
+        // ホストプログラムが拡張子を変更した場合も削除されるようにする
+        let saved = TempFile(saved);
+
+        let mut file = fs::File::open(&saved.0).map_err(AiVoiceError::file(&saved.0))?;
+        let written = io::copy(&mut file, &mut sink)?;
+        sink.flush()?;
+
+        Ok(written)
+    }
+
+    /// ボイスの読み込みを事前に済ませ、最初の再生や保存を速くします。
+    ///
+    /// 短いテキストの音声を一時ファイルに保存して破棄することで、ホストプログラムにボイスを読み込ませます。
+    ///
+    /// # 引数
+    /// * `voice_preset_name` - 読み込むボイスのボイスプリセット名。`None` の場合は現在のボイスプリセット
+    ///
+    /// # 注意
+    /// - 最初の再生時の遅延を減らすための最適化であり、効果はホストプログラムの実装に依存します。
+    /// - 処理中はテキスト入力形式、テキスト形式の入力テキスト、現在のボイスプリセットを一時的に変更します。
+    ///   処理後はいずれも元の値に戻されます。
+    ///
+    pub fn warmup(&self, voice_preset_name: Option<&str>) -> Result<()> {
+        let current_mode = self.text_edit_mode()?;
+        let current_preset = self.current_voice_preset_name()?;
+
+        let result = self.set_text_edit_mode(TextEditMode::Text).and_then(|_| {
+            let current_text = self.text()?;
+
+            let result = voice_preset_name
+                .map_or(Ok(()), |name| self.set_current_voice_preset_name(name))
+                .and_then(|_| self.set_text("あ"))
+                .and_then(|_| self.write_audio(io::sink()));
+
+            self.set_text(&current_text)?;
+            result
+        });
+
+        self.set_current_voice_preset_name(&current_preset)?;
+        self.set_text_edit_mode(current_mode)?;
+
+        result.map(|_| ())
+    }
+
+    /// テキストの読み上げ音声を保存し、実際に保存されたファイルのパスを返します。
+    ///
+    /// # 引数
+    /// * `output_dir` - 出力先ディレクトリ
+    ///
+    /// # 戻り値
+    /// 保存されたファイルのパス
+    ///
+    /// # 注意
+    /// 保存先は次の順に検出されます。
+    /// 1. `output_dir` 内に生成したファイル名（ホストプログラムが付加した拡張子を含む）のファイル
+    /// 2. `output_dir` 内で保存開始以降に更新された最新のファイル
+    ///
+    /// ホストプログラムの保存先の設定は Editor API から取得できないため、出力先ディレクトリは引数で指定します。
+    /// ホストプログラムの「音声ファイルパスの指定方法」が「ファイル命名規則」の場合、
+    /// `output_dir` にはファイル命名規則で設定されている出力先ディレクトリを指定してください。
+    ///
+    /// # エラー
+    /// 保存されたファイルを検出できなかった場合にエラーを返します。
+    ///
+    pub fn save_audio(&self, output_dir: impl AsRef<Path>) -> Result<PathBuf> {
+        let output_dir = output_dir.as_ref();
+        let started = SystemTime::now();
+
+        let stem = format!(
+            "ai_voice_{}",
+            started
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis()
+        );
+        self.save_audio_to_file(&output_dir.join(format!("{}.wav", stem)).to_string_lossy())?;
+
+        for entry in fs::read_dir(output_dir).map_err(AiVoiceError::file(output_dir))? {
+            let path = entry?.path();
+            if path
+                .file_name()
+                .is_some_and(|name| has_prefix(name, OsStr::new(&stem)))
+            {
+                return Ok(path);
+            }
+        }
+
+        newest_file_since(output_dir, started)?
+            .ok_or_else(|| AiVoiceError::SavedFileNotFound(output_dir.to_path_buf()))
+    }
+
+    /// テキストの読み上げ音声を指定されたファイルに保存し、保存の完了を待ちます。
+    ///
+    /// # 引数
+    /// * `path` - 出力先ファイルパス
+    /// * `timeout` - 待機する最大時間
+    ///
+    /// # 戻り値
+    /// 保存されたファイルのパス（ホストプログラムが拡張子を付加した場合はそれを含む）
+    ///
+    /// # 注意
+    /// `save_audio_to_file` はホストプログラムの書き込みが完了する前に終了することがあります。
+    /// このメソッドは保存の開始後、ホストプログラムの状態が `Idle` に戻り、
+    /// かつ出力先に空でないファイルが存在することを確認してから終了します。
+    /// 状態は `AiVoiceBuilder::poll_interval` で設定した間隔で取得されます。
+    ///
+    /// # エラー
+    /// `timeout` 以内に保存が完了しなかった場合や、保存されたファイルを確認できなかった場合にエラーを返します。
+    ///
+    pub fn save_audio_to_file_wait(
+        &self,
+        path: impl AsRef<Path>,
+        timeout: Duration,
+    ) -> Result<PathBuf> {
+        let path = path.as_ref();
+        let deadline = Instant::now() + timeout;
+
+        self.save_audio_to_file(&path.to_string_lossy())?;
+
+        loop {
+            if self.status()? == HostStatus::Idle {
+                if let Some(saved) = non_empty_saved_file(path) {
+                    return Ok(saved);
+                }
+            }
+
+            if Instant::now() >= deadline {
+                if self.status()? == HostStatus::Idle {
+                    return Err(AiVoiceError::SavedFileNotFound(path.to_path_buf()));
+                }
+                return Err(AiVoiceError::SaveTimeout { timeout });
+            }
+
+            thread::sleep(self.poll_interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_empty_saved_file_accepts_appended_extension() {
+        let dir = std::env::temp_dir().join(format!("ai_voice_audio_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("output");
+        assert_eq!(non_empty_saved_file(&path), None);
+
+        fs::write(dir.join("output.wav"), []).unwrap();
+        assert_eq!(non_empty_saved_file(&path), None);
+
+        fs::write(dir.join("output.wav"), [0u8; 4]).unwrap();
+        assert_eq!(non_empty_saved_file(&path), Some(dir.join("output.wav")));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn has_prefix_matches_file_name_start() {
+        assert!(has_prefix(OsStr::new("音声.wav"), OsStr::new("音声")));
+        assert!(has_prefix(
+            OsStr::new("ai_voice_1.wav.wav"),
+            OsStr::new("ai_voice_1.wav")
+        ));
+        assert!(!has_prefix(
+            OsStr::new("ai_voice_2.wav"),
+            OsStr::new("ai_voice_1")
+        ));
+        assert!(!has_prefix(OsStr::new("音"), OsStr::new("音声")));
+    }
+
+    #[test]
+    fn temp_file_is_removed_on_drop() {
+        let temp = TempFile::new("wav").unwrap();
+        fs::write(&temp.0, [0u8; 4]).unwrap();
+
+        let path = temp.0.clone();
+        drop(temp);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn warmup_restores_state() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        ai_voice.set_text_edit_mode(TextEditMode::Text)?;
+        ai_voice.set_text_force("元のテキスト")?;
+        let preset = ai_voice.current_voice_preset_name()?;
+
+        ai_voice.warmup(None)?;
+
+        assert_eq!(ai_voice.text()?, "元のテキスト");
+        assert_eq!(ai_voice.current_voice_preset_name()?, preset);
+        assert_eq!(ai_voice.text_edit_mode()?, TextEditMode::Text);
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_audio_streams_saved_bytes() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        ai_voice.set_text_edit_mode(TextEditMode::Text)?;
+        ai_voice.set_text("こんにちは")?;
+
+        let mut buffer = Vec::new();
+        let written = ai_voice.write_audio(&mut buffer)?;
+
+        assert_eq!(written, buffer.len() as u64);
+        assert!(written > 0);
+
+        Ok(())
+    }
+}
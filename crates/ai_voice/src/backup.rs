@@ -0,0 +1,135 @@
+//! `MasterControl` と `VoicePreset` を TOML / YAML ファイルに保存・読込みする機能
+//!
+//! 構造体の `serde` の設定をそのまま使用するため、キーはホストプログラムの JSON と同じ
+//! PascalCase（`PresetName`、`MergedVoiceContainer` など）になります。
+
+#[cfg(any(feature = "toml", feature = "yaml"))]
+use std::{fs, path::Path};
+
+#[cfg(any(feature = "toml", feature = "yaml"))]
+use crate::{AiVoiceError, MasterControl, Result, VoicePreset};
+
+#[cfg(feature = "toml")]
+fn save_toml<T: serde::Serialize>(value: &T, path: &Path) -> Result<()> {
+    let content = toml::to_string_pretty(value)?;
+    fs::write(path, content).map_err(AiVoiceError::file(path))
+}
+
+#[cfg(feature = "toml")]
+fn load_toml<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
+    let content = fs::read_to_string(path).map_err(AiVoiceError::file(path))?;
+    Ok(toml::from_str(&content)?)
+}
+
+#[cfg(feature = "yaml")]
+fn save_yaml<T: serde::Serialize>(value: &T, path: &Path) -> Result<()> {
+    let content = serde_yaml::to_string(value)?;
+    fs::write(path, content).map_err(AiVoiceError::file(path))
+}
+
+#[cfg(feature = "yaml")]
+fn load_yaml<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
+    let content = fs::read_to_string(path).map_err(AiVoiceError::file(path))?;
+    Ok(serde_yaml::from_str(&content)?)
+}
+
+macro_rules! impl_backup {
+    ($ty:ty) => {
+        impl $ty {
+            /// TOML ファイルに保存します。
+            ///
+            /// キーは PascalCase で出力されます。
+            ///
+            #[cfg(feature = "toml")]
+            pub fn save_to_toml(&self, path: impl AsRef<Path>) -> Result<()> {
+                save_toml(self, path.as_ref())
+            }
+
+            /// TOML ファイルから読み込みます。
+            ///
+            #[cfg(feature = "toml")]
+            pub fn load_from_toml(path: impl AsRef<Path>) -> Result<Self> {
+                load_toml(path.as_ref())
+            }
+
+            /// YAML ファイルに保存します。
+            ///
+            /// キーは PascalCase で出力されます。
+            ///
+            #[cfg(feature = "yaml")]
+            pub fn save_to_yaml(&self, path: impl AsRef<Path>) -> Result<()> {
+                save_yaml(self, path.as_ref())
+            }
+
+            /// YAML ファイルから読み込みます。
+            ///
+            #[cfg(feature = "yaml")]
+            pub fn load_from_yaml(path: impl AsRef<Path>) -> Result<Self> {
+                load_yaml(path.as_ref())
+            }
+        }
+    };
+}
+
+#[cfg(any(feature = "toml", feature = "yaml"))]
+impl_backup!(MasterControl);
+#[cfg(any(feature = "toml", feature = "yaml"))]
+impl_backup!(VoicePreset);
+
+#[cfg(all(test, any(feature = "toml", feature = "yaml")))]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+
+    fn master_control() -> MasterControl {
+        MasterControl {
+            volume: 1.25,
+            speed: 0.75,
+            pitch: 1.5,
+            pitch_range: 0.5,
+            ..test_fixtures::master_control()
+        }
+    }
+
+    fn voice_preset() -> VoicePreset {
+        VoicePreset {
+            speed: 1.1,
+            pitch_range: 1.2,
+            ..test_fixtures::merged_voice_preset("琴葉 茜")
+        }
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("ai_voice_backup_{}_{}", std::process::id(), name))
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn toml_round_trip() -> Result<()> {
+        let path = temp_path("preset.toml");
+        voice_preset().save_to_toml(&path)?;
+        assert!(fs::read_to_string(&path)?.contains("PresetName"));
+        assert_eq!(VoicePreset::load_from_toml(&path)?, voice_preset());
+
+        master_control().save_to_toml(&path)?;
+        assert_eq!(MasterControl::load_from_toml(&path)?, master_control());
+
+        fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    fn yaml_round_trip() -> Result<()> {
+        let path = temp_path("preset.yaml");
+        voice_preset().save_to_yaml(&path)?;
+        assert!(fs::read_to_string(&path)?.contains("PresetName"));
+        assert_eq!(VoicePreset::load_from_yaml(&path)?, voice_preset());
+
+        master_control().save_to_yaml(&path)?;
+        assert_eq!(MasterControl::load_from_yaml(&path)?, master_control());
+
+        fs::remove_file(&path)?;
+        Ok(())
+    }
+}
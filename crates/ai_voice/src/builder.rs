@@ -0,0 +1,233 @@
+use std::{path::PathBuf, time::Duration};
+
+use windows::Win32::System::Com::{COINIT, COINIT_APARTMENTTHREADED, COINIT_MULTITHREADED};
+
+use crate::{AiVoice, ContentLimits, Result, RetryPolicy};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[doc = "COM のアパートメントモデル"]
+pub enum ComApartment {
+    #[default]
+    #[doc = "マルチスレッドアパートメント (MTA)"]
+    MultiThreaded,
+    #[doc = "シングルスレッドアパートメント (STA)"]
+    SingleThreaded,
+}
+
+impl ComApartment {
+    pub(crate) fn to_coinit(self) -> COINIT {
+        match self {
+            ComApartment::MultiThreaded => COINIT_MULTITHREADED,
+            ComApartment::SingleThreaded => COINIT_APARTMENTTHREADED,
+        }
+    }
+}
+
+/// 待機を伴うメソッドがホストプログラムの状態を取得する既定の間隔
+///
+/// 状態の変化を検出するまでの遅延と、ホストプログラムへの問い合わせ回数の兼ね合いから
+/// 100ミリ秒としています。
+///
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// ホストプログラムが操作のない接続を自動的に解除するまでの既定の時間
+///
+pub const DEFAULT_AUTO_DISCONNECT_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// `AiVoice` の生成時の設定を指定するビルダー
+///
+#[derive(Debug, Clone)]
+pub struct AiVoiceBuilder {
+    pub(crate) apartment: ComApartment,
+    pub(crate) strict_play: bool,
+    pub(crate) poll_interval: Duration,
+    pub(crate) auto_disconnect_timeout: Duration,
+    pub(crate) host_name: Option<String>,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) restore_text_edit_mode: bool,
+    pub(crate) stall_timeout: Option<Duration>,
+    pub(crate) check_content_size: bool,
+    pub(crate) content_limits: ContentLimits,
+    pub(crate) leak_com: bool,
+    pub(crate) host_executable: Option<PathBuf>,
+}
+
+impl Default for AiVoiceBuilder {
+    fn default() -> Self {
+        AiVoiceBuilder {
+            apartment: ComApartment::default(),
+            strict_play: false,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            auto_disconnect_timeout: DEFAULT_AUTO_DISCONNECT_TIMEOUT,
+            host_name: None,
+            retry_policy: RetryPolicy::default(),
+            restore_text_edit_mode: false,
+            stall_timeout: None,
+            check_content_size: false,
+            content_limits: ContentLimits::default(),
+            leak_com: false,
+            host_executable: None,
+        }
+    }
+}
+
+impl AiVoiceBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 生成するスレッドで使用する COM のアパートメントモデルを設定します。
+    ///
+    /// 既定値は `ComApartment::MultiThreaded` です。
+    ///
+    /// # 注意
+    /// 生成するスレッドで既に COM が初期化されている場合、
+    /// そのアパートメントモデルと一致させる必要があります。
+    ///
+    pub fn apartment(mut self, apartment: ComApartment) -> Self {
+        self.apartment = apartment;
+        self
+    }
+
+    /// `AiVoice::play` で再生する内容が存在することを確認するかどうかを設定します。
+    ///
+    /// 有効な場合、`AiVoice::play` は `AiVoice::play_strict` と同様に
+    /// 再生する内容が存在しないときに `AiVoiceError::NothingToPlay` を返します。
+    /// 既定値は `false` です。
+    ///
+    pub fn strict_play(mut self, strict_play: bool) -> Self {
+        self.strict_play = strict_play;
+        self
+    }
+
+    /// 待機を伴うメソッドがホストプログラムの状態を取得する間隔を設定します。
+    ///
+    /// 既定値は `DEFAULT_POLL_INTERVAL` です。
+    /// 短くすると状態の変化を早く検出でき、長くするとホストプログラムへの問い合わせが減ります。
+    /// `ITtsControl` は状態の変化を通知するイベントを公開していないため、
+    /// 待機を伴うメソッドはすべてこの間隔での問い合わせにより状態の変化を検出します。
+    ///
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// ホストプログラムが操作のない接続を自動的に解除するまでの時間を設定します。
+    ///
+    /// `AiVoice::time_until_auto_disconnect` の計算に使用されます。
+    /// 既定値は `DEFAULT_AUTO_DISCONNECT_TIMEOUT` です。
+    /// ホストプログラムのバージョンによって時間が異なる場合に変更してください。
+    ///
+    pub fn auto_disconnect_timeout(mut self, timeout: Duration) -> Self {
+        self.auto_disconnect_timeout = timeout;
+        self
+    }
+
+    /// 使用するホストプログラムの名前を設定します。
+    ///
+    /// 既定では利用可能なホストプログラムのうち最初のものを使用します。
+    ///
+    /// # 注意
+    /// 完全に一致するホスト名が存在しない場合、NFKC 正規化と小文字への変換を
+    /// 両者に行ってから比較するため、全角・半角や大文字・小文字の違いは無視されます。
+    /// 一致するホスト名が存在しない場合、`build` は `AiVoiceError::HostNotFound` を返します。
+    ///
+    pub fn host_name(mut self, host_name: &str) -> Self {
+        self.host_name = Some(host_name.to_string());
+        self
+    }
+
+    /// 再試行を伴うメソッドが使用する再試行の方針を設定します。
+    ///
+    /// 既定値は `RetryPolicy::default()`（500ミリ秒間隔で最大5回）です。
+    ///
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// `AiVoice::play_text` / `AiVoice::play_list` の再生後に元のテキスト入力形式に戻すかどうかを設定します。
+    ///
+    /// 有効な場合、これらのメソッドは再生の完了を待ってから終了します。
+    /// 既定値は `false` です。
+    ///
+    pub fn restore_text_edit_mode(mut self, restore_text_edit_mode: bool) -> Self {
+        self.restore_text_edit_mode = restore_text_edit_mode;
+        self
+    }
+
+    /// 再生の完了を待つメソッドで、ホストプログラムの停滞を検出するまでの猶予を設定します。
+    ///
+    /// COM API からは再生位置を取得できないため、`AiVoice::play_time` から推定した再生の終了時刻を
+    /// `stall_timeout` 以上過ぎてもホストプログラムが処理中のままの場合に停滞とみなし、
+    /// `AiVoiceError::PlaybackStalled` を返します。
+    /// 推定値には再生開始までの遅延が含まれないため、数秒程度の余裕を持たせてください。
+    /// 既定では検出を行いません。
+    ///
+    /// # 引数
+    /// * `stall_timeout` - 推定終了時刻からの猶予
+    ///
+    pub fn stall_timeout(mut self, stall_timeout: Duration) -> Self {
+        self.stall_timeout = Some(stall_timeout);
+        self
+    }
+
+    /// テキストとリスト形式の行を設定する前に、大きさが上限を超えないか確認するかどうかを設定します。
+    ///
+    /// 有効な場合、`AiVoice::set_text` などはテキストの長さが、
+    /// `AiVoice::add_list_item` などは追加後の行数が `content_limits` で設定した上限を超えるときに
+    /// `AiVoiceError::ContentTooLarge` を返します。
+    /// 既定値は `false` です。
+    ///
+    pub fn check_content_size(mut self, check_content_size: bool) -> Self {
+        self.check_content_size = check_content_size;
+        self
+    }
+
+    /// `check_content_size` で確認する大きさの上限を設定します。
+    ///
+    /// 既定値は `MAX_TEXT_UTF16_LEN` と `MAX_LIST_ROWS` です。
+    /// これらは暫定的な目安であるため、使用するホストプログラムで確認した値がある場合はそれを設定してください。
+    ///
+    pub fn content_limits(mut self, content_limits: ContentLimits) -> Self {
+        self.content_limits = content_limits;
+        self
+    }
+
+    /// 破棄時に `CoUninitialize` を呼び出さないようにするかどうかを設定します。
+    ///
+    /// 有効な場合、`build` で初期化した COM は `AiVoice` を破棄しても解放されず、
+    /// 生成したスレッドでプロセスの終了まで初期化されたままになります（意図的なリーク）。
+    /// 多数のインスタンスを生成するテストなど、プロセスの開始時と終了時に
+    /// COM を一括で管理する場合に、破棄の順序による問題を避けるために使用してください。
+    /// 既定値は `false` です。
+    ///
+    pub fn leak_com(mut self, leak_com: bool) -> Self {
+        self.leak_com = leak_com;
+        self
+    }
+
+    /// `AiVoice::start_host` で COM 経由の起動に失敗したときに直接起動するホストプログラムの実行ファイルを設定します。
+    ///
+    /// レジストリに登録された COM サーバーが別の場所を指している、または登録されていない
+    /// ポータブル版などのインストールで使用します。
+    /// 起動は次の順に試行されます。
+    /// 1. `ITtsControl::StartHost` による COM 経由の起動
+    /// 2. 1が失敗した場合、`host_executable` を直接起動し、起動完了を待ってから接続を再試行
+    ///
+    /// 既定では直接起動を行いません。
+    ///
+    /// # 引数
+    /// * `host_executable` - ホストプログラムの実行ファイルのパス
+    ///
+    pub fn host_executable(mut self, host_executable: PathBuf) -> Self {
+        self.host_executable = Some(host_executable);
+        self
+    }
+
+    /// 設定に従って `AiVoice` を生成します。
+    ///
+    pub fn build(&self) -> Result<AiVoice> {
+        AiVoice::from_builder(self)
+    }
+}
@@ -0,0 +1,307 @@
+use std::io;
+
+use crate::{AiVoice, AiVoiceError, ListItem, Result};
+
+/// CSV のヘッダー行
+const CSV_HEADER: [&str; 2] = ["preset", "text"];
+
+/// CSV のフィールドを書き出します。
+///
+/// `"`、`,`、改行を含むフィールドは `"` で囲み、`"` は `""` にエスケープします。
+///
+fn write_csv_field(writer: &mut impl io::Write, field: &str) -> io::Result<()> {
+    if field.contains(['"', ',', '\n', '\r']) {
+        write!(writer, "\"{}\"", field.replace('"', "\"\""))
+    } else {
+        write!(writer, "{}", field)
+    }
+}
+
+/// CSV の1行を書き出します。
+fn write_csv_record(writer: &mut impl io::Write, fields: &[&str]) -> io::Result<()> {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            write!(writer, ",")?;
+        }
+        write_csv_field(writer, field)?;
+    }
+    write!(writer, "\r\n")
+}
+
+/// リスト形式の行を CSV として書き出します。
+fn write_list_csv(mut writer: impl io::Write, items: &[ListItem]) -> io::Result<()> {
+    write_csv_record(&mut writer, &CSV_HEADER)?;
+    for item in items {
+        write_csv_record(&mut writer, &[&item.voice_preset_name, &item.text])?;
+    }
+    writer.flush()
+}
+
+/// CSV を解析します。
+///
+/// `"` で囲まれたフィールドでは `,` と改行をそのまま含めることができ、`""` は `"` として扱われます。
+/// 空行は無視されます。
+///
+/// # 戻り値
+/// `(レコードの開始行番号, フィールドのベクター)` のベクター
+///
+fn parse_csv(content: &str) -> Result<Vec<(usize, Vec<String>)>> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => {
+                    quoted = false;
+                    if !matches!(chars.peek(), None | Some(',' | '\r' | '\n')) {
+                        return Err(AiVoiceError::CsvMalformed {
+                            line,
+                            reason: "unexpected character after closing quote".to_string(),
+                        });
+                    }
+                }
+                _ => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => quoted = true,
+            '"' => {
+                return Err(AiVoiceError::CsvMalformed {
+                    line,
+                    reason: "unexpected quote in unquoted field".to_string(),
+                })
+            }
+            ',' => fields.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\r' | '\n' => {
+                if !fields.is_empty() || !field.is_empty() {
+                    fields.push(std::mem::take(&mut field));
+                    records.push((record_line, std::mem::take(&mut fields)));
+                }
+                line += 1;
+                record_line = line;
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(AiVoiceError::CsvMalformed {
+            line: record_line,
+            reason: "unterminated quoted field".to_string(),
+        });
+    }
+    if !fields.is_empty() || !field.is_empty() {
+        fields.push(field);
+        records.push((record_line, fields));
+    }
+
+    Ok(records)
+}
+
+/// CSV からリスト形式の行を読み込みます。
+///
+/// 先頭のレコードがヘッダー行と一致する場合は読み飛ばします。
+/// 各レコードは `preset,text` の2フィールドで、ボイスプリセット名は `known_presets` に含まれている必要があります。
+///
+fn parse_list_csv(content: &str, known_presets: &[String]) -> Result<Vec<ListItem>> {
+    let mut records = parse_csv(content)?.into_iter().peekable();
+
+    if records
+        .peek()
+        .is_some_and(|(_, fields)| fields.iter().map(String::as_str).eq(CSV_HEADER))
+    {
+        records.next();
+    }
+
+    records
+        .map(|(line, fields)| {
+            let [preset, text]: [String; 2] =
+                fields
+                    .try_into()
+                    .map_err(|fields: Vec<String>| AiVoiceError::CsvMalformed {
+                        line,
+                        reason: format!("expected 2 fields, found {}", fields.len()),
+                    })?;
+
+            if !known_presets.contains(&preset) {
+                return Err(AiVoiceError::CsvUnknownPreset { line, name: preset });
+            }
+
+            Ok(ListItem::new(preset, text))
+        })
+        .collect()
+}
+
+impl AiVoice {
+    /// リスト形式の行を CSV として書き出します。
+    ///
+    /// `preset,text` のヘッダー行に続いて、1行ごとにボイスプリセット名とテキストを書き出します。
+    ///
+    /// # 引数
+    /// * `writer` - 書き出し先
+    ///
+    /// # 注意
+    /// - `"`、`,`、改行を含むフィールドは `"` で囲まれます。
+    /// - 各行を順に単一選択して取得するため、処理中は選択状態が変化します。
+    ///   処理後は元の選択状態に戻されます。
+    ///
+    pub fn export_list_csv(&self, writer: impl io::Write) -> Result<()> {
+        let items = self.list_items()?;
+        Ok(write_list_csv(writer, &items)?)
+    }
+
+    /// CSV からリスト形式の行を追加します。
+    ///
+    /// `export_list_csv` で書き出した形式の CSV を読み込めます。
+    ///
+    /// # 引数
+    /// * `reader` - 読み込み元
+    /// * `clear_existing` - 追加する前に既存のリスト形式の行をすべて削除するかどうか
+    ///
+    /// # 戻り値
+    /// 追加した行数
+    ///
+    /// # エラー
+    /// CSV の形式が不正な場合や、登録されていないボイスプリセット名が含まれる場合は、
+    /// 該当する行番号を含むエラーを返します。この場合、リスト形式の行は変更されません。
+    ///
+    /// # 注意
+    /// 先頭行が `preset,text` の場合はヘッダー行として読み飛ばします。
+    ///
+    pub fn import_list_csv(
+        &self,
+        mut reader: impl io::Read,
+        clear_existing: bool,
+    ) -> Result<usize> {
+        let mut content = String::new();
+        reader.read_to_string(&mut content)?;
+
+        let items = parse_list_csv(&content, &self.voice_preset_names()?)?;
+        if clear_existing {
+            self.replace_list(&items)?;
+        } else {
+            self.add_list_items(&items)?;
+        }
+
+        Ok(items.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_csv(items: &[(&str, &str)]) -> String {
+        let items = items
+            .iter()
+            .map(|&(preset, text)| ListItem::new(preset, text))
+            .collect::<Vec<_>>();
+
+        let mut buf = Vec::new();
+        write_list_csv(&mut buf, &items).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn write_list_csv_escapes_fields() {
+        let csv = to_csv(&[
+            ("琴葉 茜", "こんにちは"),
+            ("琴葉 葵", "「はい」、\"いいえ\""),
+            ("琴葉 茜", "1, 2\n3"),
+        ]);
+
+        assert_eq!(
+            csv,
+            "preset,text\r\n\
+             琴葉 茜,こんにちは\r\n\
+             琴葉 葵,\"「はい」、\"\"いいえ\"\"\"\r\n\
+             琴葉 茜,\"1, 2\n3\"\r\n"
+        );
+    }
+
+    #[test]
+    fn write_list_csv_empty_list_has_header_only() {
+        assert_eq!(to_csv(&[]), "preset,text\r\n");
+    }
+
+    fn known_presets() -> Vec<String> {
+        vec!["琴葉 茜".to_string(), "琴葉 葵".to_string()]
+    }
+
+    #[test]
+    fn parse_list_csv_handles_quoted_multiline_fields() {
+        let content = "preset,text\r\n\
+                       琴葉 茜,こんにちは\r\n\
+                       \r\n\
+                       \"琴葉 葵\",\"1行目\n\"\"2行目\"\", です\"\n\
+                       琴葉 茜,";
+
+        let items = parse_list_csv(content, &known_presets()).unwrap();
+        assert_eq!(
+            items,
+            vec![
+                ListItem::new("琴葉 茜", "こんにちは"),
+                ListItem::new("琴葉 葵", "1行目\n\"2行目\", です"),
+                ListItem::new("琴葉 茜", ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_list_csv_round_trips_export() {
+        let items = vec![
+            ListItem::new("琴葉 茜", "a,b"),
+            ListItem::new("琴葉 葵", "\"quoted\"\r\nnext"),
+        ];
+
+        let mut buf = Vec::new();
+        write_list_csv(&mut buf, &items).unwrap();
+
+        let parsed = parse_list_csv(std::str::from_utf8(&buf).unwrap(), &known_presets()).unwrap();
+        assert_eq!(parsed, items);
+    }
+
+    #[test]
+    fn parse_list_csv_reports_line_numbers() {
+        let err = |content: &str| {
+            parse_list_csv(content, &known_presets())
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            err("琴葉 茜,\"a\nb\"\n琴葉 葵,x,y\n"),
+            "Malformed CSV at line 3: expected 2 fields, found 3"
+        );
+        assert_eq!(
+            err("琴葉 茜,a\n紲星 あかり,b\n"),
+            "Unknown voice preset at line 2: 紲星 あかり"
+        );
+        assert_eq!(
+            err("琴葉 茜,a\n琴葉 葵,\"b\n"),
+            "Malformed CSV at line 2: unterminated quoted field"
+        );
+        assert_eq!(
+            err("琴葉 茜,a\"b\n"),
+            "Malformed CSV at line 1: unexpected quote in unquoted field"
+        );
+    }
+}
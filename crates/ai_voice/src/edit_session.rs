@@ -0,0 +1,194 @@
+use crate::{AiVoice, MasterControl, Result, VoicePreset};
+
+/// 取得時の値と編集中の値を保持し、変更の有無を追跡します。
+///
+#[derive(Debug, Clone)]
+pub struct Tracked<T> {
+    original: T,
+    current: T,
+}
+
+impl<T: Clone + PartialEq> Tracked<T> {
+    pub fn new(value: T) -> Self {
+        Tracked {
+            original: value.clone(),
+            current: value,
+        }
+    }
+
+    /// 編集中の値を取得します。
+    ///
+    pub fn get(&self) -> &T {
+        &self.current
+    }
+
+    /// 編集中の値を変更可能な参照として取得します。
+    ///
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.current
+    }
+
+    /// 編集中の値が取得時の値から変更されているかどうかを取得します。
+    ///
+    pub fn is_dirty(&self) -> bool {
+        self.original != self.current
+    }
+
+    /// 編集中の値を取得時の値に戻します。
+    ///
+    pub fn revert(&mut self) {
+        self.current = self.original.clone();
+    }
+
+    /// 変更されている場合のみ `write` で編集中の値を書き込みます。
+    ///
+    /// 書き込みに成功した場合、編集中の値を新たな取得時の値として扱います。
+    ///
+    fn commit_with(&mut self, write: impl FnOnce(&T) -> Result<()>) -> Result<bool> {
+        if !self.is_dirty() {
+            return Ok(false);
+        }
+
+        write(&self.current)?;
+        self.original = self.current.clone();
+        Ok(true)
+    }
+}
+
+/// マスターコントロールとボイスプリセットの編集セッション
+///
+/// 開始時の設定を保持し、`commit` で変更された設定のみをホストプログラムに適用します。
+/// 設定画面のスライダー操作のたびにホストプログラムを呼び出すことを避けるために使用します。
+///
+pub struct EditSession<'a> {
+    ai_voice: &'a AiVoice,
+    master_control: Tracked<MasterControl>,
+    voice_preset: Option<Tracked<VoicePreset>>,
+}
+
+impl<'a> EditSession<'a> {
+    /// 編集中のマスターコントロールを取得します。
+    ///
+    pub fn master_control(&self) -> &MasterControl {
+        self.master_control.get()
+    }
+
+    /// 編集中のマスターコントロールを変更可能な参照として取得します。
+    ///
+    pub fn master_control_mut(&mut self) -> &mut MasterControl {
+        self.master_control.get_mut()
+    }
+
+    /// 編集中のボイスプリセットを取得します。
+    ///
+    pub fn voice_preset(&self) -> Option<&VoicePreset> {
+        self.voice_preset.as_ref().map(Tracked::get)
+    }
+
+    /// 編集中のボイスプリセットを変更可能な参照として取得します。
+    ///
+    pub fn voice_preset_mut(&mut self) -> Option<&mut VoicePreset> {
+        self.voice_preset.as_mut().map(Tracked::get_mut)
+    }
+
+    /// 開始時または直前の `commit` 時から設定が変更されているかどうかを取得します。
+    ///
+    pub fn is_dirty(&self) -> bool {
+        self.master_control.is_dirty() || self.voice_preset.as_ref().is_some_and(Tracked::is_dirty)
+    }
+
+    /// 編集中の設定を開始時または直前の `commit` 時の設定に戻します。
+    ///
+    pub fn revert(&mut self) {
+        self.master_control.revert();
+        if let Some(voice_preset) = &mut self.voice_preset {
+            voice_preset.revert();
+        }
+    }
+
+    /// 変更された設定をホストプログラムに適用します。
+    ///
+    /// # 戻り値
+    /// いずれかの設定を適用した場合は `true`
+    ///
+    /// # 注意
+    /// マスターコントロールは `AiVoice::apply_master_control`、
+    /// ボイスプリセットは `VoicePreset::clamp` で値を制限してから適用されます。
+    ///
+    pub fn commit(&mut self) -> Result<bool> {
+        let ai_voice = self.ai_voice;
+
+        let mut committed = self
+            .master_control
+            .commit_with(|master_control| ai_voice.apply_master_control(master_control))?;
+
+        if let Some(voice_preset) = &mut self.voice_preset {
+            committed |= voice_preset.commit_with(|voice_preset| {
+                let mut voice_preset = voice_preset.clone();
+                voice_preset.clamp();
+                ai_voice.set_voice_preset(&voice_preset)
+            })?;
+        }
+
+        Ok(committed)
+    }
+}
+
+impl AiVoice {
+    /// マスターコントロールと、指定された場合はボイスプリセットの編集セッションを開始します。
+    ///
+    /// # 引数
+    /// * `preset_name` - 編集するボイスプリセット名。`None` の場合はマスターコントロールのみを編集します。
+    ///
+    pub fn edit_session(&self, preset_name: Option<&str>) -> Result<EditSession<'_>> {
+        Ok(EditSession {
+            ai_voice: self,
+            master_control: Tracked::new(self.master_control()?),
+            voice_preset: preset_name
+                .map(|name| self.voice_preset(name).map(Tracked::new))
+                .transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::master_control;
+
+    #[test]
+    fn commit_skips_write_when_unchanged() -> Result<()> {
+        let mut tracked = Tracked::new(master_control());
+        let mut writes = 0;
+
+        tracked.get_mut().speed = 1.0;
+        assert!(!tracked.commit_with(|_| {
+            writes += 1;
+            Ok(())
+        })?);
+        assert_eq!(writes, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn commit_writes_once_after_change() -> Result<()> {
+        let mut tracked = Tracked::new(master_control());
+        let mut writes = 0;
+
+        tracked.get_mut().speed = 1.5;
+        assert!(tracked.is_dirty());
+
+        for _ in 0..2 {
+            tracked.commit_with(|master_control| {
+                assert_eq!(master_control.speed, 1.5);
+                writes += 1;
+                Ok(())
+            })?;
+        }
+        assert_eq!(writes, 1);
+        assert!(!tracked.is_dirty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,442 @@
+use std::{io, ops::Range, path::PathBuf, time::Duration};
+
+use windows::{
+    core::HRESULT,
+    Win32::Foundation::{
+        CO_E_OBJNOTCONNECTED, RPC_E_CALL_REJECTED, RPC_E_CHANGED_MODE, RPC_E_DISCONNECTED,
+        RPC_E_SERVERCALL_RETRYLATER, RPC_E_SERVER_DIED, RPC_E_SERVER_DIED_DNE,
+    },
+};
+
+use crate::{ChangedField, ContentKind, PauseKind, TextEditMode};
+
+/// ホストプログラムが既に終了していることを示す HRESULT の一覧
+const HOST_GONE_HRESULTS: &[HRESULT] = &[
+    // RPC_S_SERVER_UNAVAILABLE: RPC サーバーを利用できません
+    HRESULT(0x800706BA_u32 as _),
+    // RPC_S_CALL_FAILED: リモート プロシージャ コールに失敗しました
+    HRESULT(0x800706BE_u32 as _),
+    RPC_E_DISCONNECTED,
+    RPC_E_SERVER_DIED,
+    RPC_E_SERVER_DIED_DNE,
+    CO_E_OBJNOTCONNECTED,
+];
+
+/// ホストプログラムが呼び出しを受け付けられない状態であることを示す HRESULT の一覧
+///
+/// ホストプログラムがモーダルダイアログ（更新の確認など）を表示している間、
+/// COM のメッセージフィルターにより呼び出しが拒否されるとこれらの HRESULT が返されます。
+const MODAL_BLOCKING_HRESULTS: &[HRESULT] = &[RPC_E_CALL_REJECTED, RPC_E_SERVERCALL_RETRYLATER];
+
+/// このクレートのメソッドが返す `Result`
+pub type Result<T, E = AiVoiceError> = std::result::Result<T, E>;
+
+/// このクレートが返すエラーの種類
+///
+/// 各メソッドは `Result<T, AiVoiceError>` を返すため、エラーの種類を `match` で判別できます。
+/// COM の呼び出しで発生したその他のエラーは `AiVoiceError::Com` として返されます。
+///
+/// ```no_run
+/// use ai_voice::{AiVoice, AiVoiceError};
+///
+/// # fn main() -> Result<(), AiVoiceError> {
+/// let ai_voice = AiVoice::new()?;
+/// match ai_voice.text() {
+///     Ok(text) => println!("{}", text),
+///     Err(AiVoiceError::NotConnected) => ai_voice.connect()?,
+///     Err(AiVoiceError::Com(e)) => println!("COM error: {:?}", e.code()),
+///     Err(e) => println!("{}", e),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, thiserror::Error)]
+pub enum AiVoiceError {
+    #[error("File already exists: {}", .0.display())]
+    #[doc = "出力先のファイルが既に存在する"]
+    FileExists(PathBuf),
+
+    #[error("Voice preset already exists: {0}")]
+    #[doc = "同じ名前のボイスプリセットが既に存在する"]
+    PresetAlreadyExists(String),
+
+    #[error("Voice not found: {0}")]
+    #[doc = "指定されたボイス名のボイスが存在しない"]
+    VoiceNotFound(String),
+
+    #[error("The {kind} of {size} exceeds the limit of {limit}")]
+    #[doc = "テキストの長さやリスト形式の行数が上限を超える"]
+    ContentTooLarge {
+        #[doc = "内容の種類"]
+        kind: ContentKind,
+        #[doc = "指定された内容の大きさ"]
+        size: usize,
+        #[doc = "上限"]
+        limit: usize,
+    },
+
+    #[error("{}", batch_failed_message(*.modified, .failures))]
+    #[doc = "一括処理で一部のボイスプリセットの処理に失敗した"]
+    PresetBatchFailed {
+        #[doc = "変更を書き戻したボイスプリセットの数"]
+        modified: usize,
+        #[doc = "失敗したボイスプリセット名とエラーメッセージのリスト"]
+        failures: Vec<(String, String)>,
+    },
+
+    #[error("The host did not apply the voice preset field {field:?}")]
+    #[doc = "書き込んだボイスプリセットの設定がホストプログラムに反映されなかった"]
+    PresetNotApplied {
+        #[doc = "反映されなかった項目"]
+        field: ChangedField,
+    },
+
+    #[error("The API is not initialized")]
+    #[doc = "API が初期化されていない"]
+    NotInitialized,
+
+    #[error("The host is not running")]
+    #[doc = "ホストプログラムが起動していない"]
+    HostNotRunning,
+
+    #[error("Not connected to the host")]
+    #[doc = "ホストプログラムに接続していない"]
+    NotConnected,
+
+    #[error("There is no text or list item to play")]
+    #[doc = "再生する内容が存在しない"]
+    NothingToPlay,
+
+    #[error("Unknown host status: {0}")]
+    #[doc = "ホストプログラムが未知の状態の値を返した"]
+    UnknownStatus(i32),
+
+    #[error("Unknown text edit mode: {0}")]
+    #[doc = "ホストプログラムが未知のテキスト入力形式の値を返した"]
+    UnknownTextEditMode(i32),
+
+    #[error("Failed to convert JSON: {0}")]
+    #[doc = "JSON の解析または生成に失敗した"]
+    Json(#[from] serde_json::Error),
+
+    #[error("List range (start: {start}, length: {length}) is out of range (list count: {count})")]
+    #[doc = "リスト形式のインデックスが範囲外である"]
+    IndexOutOfRange {
+        #[doc = "開始行のインデックス"]
+        start: i32,
+        #[doc = "行数"]
+        length: i32,
+        #[doc = "リスト形式の行数"]
+        count: i32,
+    },
+
+    #[error("The {kind} pause of {value} ms exceeds the maximum of {max} ms")]
+    #[doc = "ポーズの長さが指定可能な範囲外である"]
+    PauseOutOfRange {
+        #[doc = "ポーズの種類"]
+        kind: PauseKind,
+        #[doc = "指定された長さ (ms)"]
+        value: u16,
+        #[doc = "指定可能な最大の長さ (ms)"]
+        max: u16,
+    },
+
+    #[error(
+        "The host did not switch the text edit mode to {requested:?} (current mode: {actual:?})"
+    )]
+    #[doc = "テキスト編集モードの切り替えが反映されなかった"]
+    ModeSwitchFailed {
+        #[doc = "設定したテキスト編集モード"]
+        requested: TextEditMode,
+        #[doc = "設定後に取得したテキスト編集モード"]
+        actual: TextEditMode,
+    },
+
+    #[error("Host not found: {name} (available hosts after normalization: {candidates:?})")]
+    #[doc = "指定されたホスト名に一致するホストプログラムが存在しない"]
+    HostNotFound {
+        #[doc = "指定されたホスト名"]
+        name: String,
+        #[doc = "正規化した利用可能なホスト名のリスト"]
+        candidates: Vec<String>,
+    },
+
+    #[error("The host has been busy for {busy_for:?}, well past the estimated end of playback")]
+    #[doc = "再生の推定終了時刻を過ぎてもホストプログラムが処理中のままである"]
+    PlaybackStalled {
+        #[doc = "処理中の状態が続いている時間"]
+        busy_for: Duration,
+    },
+
+    #[error("Playback did not finish within {timeout:?}")]
+    #[doc = "指定された時間内に再生が完了しなかった"]
+    PlaybackTimeout {
+        #[doc = "待機した最大時間"]
+        timeout: Duration,
+    },
+
+    #[error(
+        "The host rejected the call, probably because a dialog is open in A.I.VOICE Editor; \
+         close the dialog and try again: {0}"
+    )]
+    #[doc = "ホストプログラムがダイアログを表示しているため、呼び出しを受け付けられない"]
+    HostModalBlocking(#[source] windows::core::Error),
+
+    #[error("{}", com_init_message(.0))]
+    #[doc = "COM の初期化に失敗した"]
+    ComInit(#[source] windows::core::Error),
+
+    #[error("Failed to create the TtsControl instance (is A.I.VOICE Editor installed?): {0}")]
+    #[doc = "TtsControl の生成に失敗した"]
+    ControlCreate(#[source] windows::core::Error),
+
+    #[error(transparent)]
+    #[doc = "COM の呼び出しに失敗した"]
+    Com(#[from] windows::core::Error),
+
+    #[error("No available host programs were found")]
+    #[doc = "利用可能なホストプログラムが存在しない"]
+    NoHostAvailable,
+
+    #[error("Failed to launch the host executable {}: {source}", .path.display())]
+    #[doc = "ホストプログラムの実行ファイルの起動に失敗した"]
+    HostLaunch {
+        #[doc = "実行ファイルのパス"]
+        path: PathBuf,
+        #[doc = "発生したエラー"]
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("Timed out waiting for the host to start")]
+    #[doc = "ホストプログラムの起動の完了を待機する時間が経過した"]
+    HostStartTimeout,
+
+    #[error("Could not find a non-empty saved audio file at {}", .0.display())]
+    #[doc = "保存された音声ファイルが見つからないか、空である"]
+    SavedFileNotFound(PathBuf),
+
+    #[error("Saving the audio did not finish within {timeout:?}")]
+    #[doc = "指定された時間内に音声の保存が完了しなかった"]
+    SaveTimeout {
+        #[doc = "待機した最大時間"]
+        timeout: Duration,
+    },
+
+    #[error("This operation requires the list edit mode")]
+    #[doc = "リスト形式でのみ行える操作をテキスト形式で行った"]
+    ListModeRequired,
+
+    #[error("The frame rate must be a positive finite number: {0}")]
+    #[doc = "フレームレートが正の有限値でない"]
+    InvalidFrameRate(f64),
+
+    #[error("The selection {0:?} does not fall on character boundaries of the text")]
+    #[doc = "テキスト形式の選択範囲が文字の境界にない"]
+    SelectionNotOnCharBoundary(Range<usize>),
+
+    #[error("Failed to parse voice preset: the voice name is not recognizable")]
+    #[doc = "ボイスプリセットの JSON からボイス名を取得できない"]
+    UnrecognizableVoicePreset,
+
+    #[error("Invalid version string: {0}")]
+    #[doc = "バージョン文字列の形式が不正である"]
+    InvalidVersion(String),
+
+    #[error("Malformed CSV at line {line}: {reason}")]
+    #[doc = "CSV の形式が不正である"]
+    CsvMalformed {
+        #[doc = "レコードの開始行番号"]
+        line: usize,
+        #[doc = "不正な理由"]
+        reason: String,
+    },
+
+    #[error("Unknown voice preset at line {line}: {name}")]
+    #[doc = "CSV に登録されていないボイスプリセット名が含まれる"]
+    CsvUnknownPreset {
+        #[doc = "レコードの開始行番号"]
+        line: usize,
+        #[doc = "ボイスプリセット名"]
+        name: String,
+    },
+
+    #[error("Failed to access {}: {source}", .path.display())]
+    #[doc = "ファイルの読み書きに失敗した"]
+    File {
+        #[doc = "ファイルのパス"]
+        path: PathBuf,
+        #[doc = "発生したエラー"]
+        #[source]
+        source: io::Error,
+    },
+
+    #[error(transparent)]
+    #[doc = "入出力に失敗した"]
+    Io(#[from] io::Error),
+
+    #[cfg(feature = "toml")]
+    #[error("Failed to serialize as TOML: {0}")]
+    #[doc = "TOML への変換に失敗した"]
+    TomlSerialize(#[from] toml::ser::Error),
+
+    #[cfg(feature = "toml")]
+    #[error("Failed to parse TOML: {0}")]
+    #[doc = "TOML の解析に失敗した"]
+    TomlParse(#[from] toml::de::Error),
+
+    #[cfg(feature = "yaml")]
+    #[error("Failed to convert YAML: {0}")]
+    #[doc = "YAML の解析または生成に失敗した"]
+    Yaml(#[from] serde_yaml::Error),
+}
+
+impl AiVoiceError {
+    /// `path` の読み書きで発生した `io::Error` を変換する関数を返します。
+    pub(crate) fn file(path: &std::path::Path) -> impl FnOnce(io::Error) -> Self + '_ {
+        move |source| AiVoiceError::File {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+}
+
+fn com_init_message(e: &windows::core::Error) -> String {
+    if e.code() == RPC_E_CHANGED_MODE {
+        format!(
+            "COM is already initialized on this thread with a different apartment model; \
+             use `AiVoiceBuilder::apartment` to match it: {}",
+            e
+        )
+    } else {
+        format!("Failed to initialize COM: {}", e)
+    }
+}
+
+fn batch_failed_message(modified: usize, failures: &[(String, String)]) -> String {
+    format!(
+        "Failed to process {} voice presets ({} modified): {}",
+        failures.len(),
+        modified,
+        failures
+            .iter()
+            .map(|(name, e)| format!("{}: {}", name, e))
+            .collect::<Vec<_>>()
+            .join("; ")
+    )
+}
+
+/// エラーがホストプログラムのモーダルダイアログによるものと推定されるかどうかを判定します。
+pub(crate) fn is_modal_blocking(e: &windows::core::Error) -> bool {
+    MODAL_BLOCKING_HRESULTS.contains(&e.code())
+}
+
+/// エラーがホストプログラムの終了によるものかどうかを判定します。
+pub(crate) fn is_host_gone(e: &windows::core::Error) -> bool {
+    HOST_GONE_HRESULTS.contains(&e.code())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use windows::Win32::System::Com::{CoInitializeEx, CoUninitialize, COINIT_APARTMENTTHREADED};
+
+    use super::*;
+    use crate::{AiVoice, ComApartment};
+
+    #[test]
+    fn changed_mode_message_points_at_builder() {
+        let e = AiVoiceError::ComInit(windows::core::Error::from(RPC_E_CHANGED_MODE));
+        assert!(e.to_string().contains("AiVoiceBuilder::apartment"));
+    }
+
+    #[test]
+    fn preset_batch_failed_lists_failures() {
+        let e = AiVoiceError::PresetBatchFailed {
+            modified: 3,
+            failures: vec![
+                ("a".to_string(), "invalid".to_string()),
+                ("b".to_string(), "busy".to_string()),
+            ],
+        };
+        assert_eq!(
+            e.to_string(),
+            "Failed to process 2 voice presets (3 modified): a: invalid; b: busy"
+        );
+    }
+
+    #[test]
+    fn com_errors_convert_to_com_variant() {
+        let convert = || -> Result<()> { Err(windows::core::Error::from(RPC_E_DISCONNECTED))? };
+        assert!(matches!(
+            convert(),
+            Err(AiVoiceError::Com(e)) if e.code() == RPC_E_DISCONNECTED
+        ));
+        assert!(matches!(
+            "1.x".parse::<crate::Version>(),
+            Err(AiVoiceError::InvalidVersion(s)) if s == "1.x"
+        ));
+    }
+
+    #[test]
+    fn modal_blocking_hresults() {
+        assert!(is_modal_blocking(&windows::core::Error::from(
+            RPC_E_CALL_REJECTED
+        )));
+        assert!(is_modal_blocking(&windows::core::Error::from(
+            RPC_E_SERVERCALL_RETRYLATER
+        )));
+        assert!(!is_modal_blocking(&windows::core::Error::from(
+            RPC_E_DISCONNECTED
+        )));
+    }
+
+    #[test]
+    fn host_gone_hresults() {
+        assert!(is_host_gone(&windows::core::Error::from(
+            RPC_E_DISCONNECTED
+        )));
+        assert!(is_host_gone(&windows::core::Error::from(HRESULT(
+            0x800706BA_u32 as _
+        ))));
+        assert!(!is_host_gone(&windows::core::Error::from(
+            RPC_E_CHANGED_MODE
+        )));
+    }
+
+    #[test]
+    fn disconnect_after_host_terminated() -> anyhow::Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        ai_voice.terminate_host()?;
+        ai_voice.disconnect()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn apartment_conflict_maps_to_com_init() {
+        thread::spawn(|| unsafe {
+            CoInitializeEx(None, COINIT_APARTMENTTHREADED).unwrap();
+
+            let e = AiVoice::builder()
+                .apartment(ComApartment::MultiThreaded)
+                .build()
+                .err()
+                .unwrap();
+
+            match &e {
+                AiVoiceError::ComInit(e) => assert_eq!(e.code(), RPC_E_CHANGED_MODE),
+                _ => panic!("unexpected error: {:?}", e),
+            }
+
+            CoUninitialize();
+        })
+        .join()
+        .unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+//! ライフサイクルメソッドで発生した COM エラーを Windows のイベントログに記録します。
+//!
+//! `eventlog` 機能が有効な場合のみ、アプリケーションログにイベントソース `ai_voice` として記録されます。
+
+/// `result` がエラーの場合、メソッド名と HRESULT をイベントログに記録します。
+pub(crate) fn report<T>(
+    method: &str,
+    result: windows::core::Result<T>,
+) -> windows::core::Result<T> {
+    #[cfg(feature = "eventlog")]
+    if let Err(e) = &result {
+        write_error_event(method, e);
+    }
+
+    #[cfg(not(feature = "eventlog"))]
+    let _ = method;
+
+    result
+}
+
+/// 警告メッセージをイベントログに記録します。
+pub(crate) fn warn(message: &str) {
+    #[cfg(feature = "eventlog")]
+    write_event(
+        windows::Win32::System::EventLog::EVENTLOG_WARNING_TYPE,
+        message,
+    );
+
+    #[cfg(not(feature = "eventlog"))]
+    let _ = message;
+}
+
+#[cfg(feature = "eventlog")]
+fn write_error_event(method: &str, e: &windows::core::Error) {
+    write_event(
+        windows::Win32::System::EventLog::EVENTLOG_ERROR_TYPE,
+        &format!(
+            "{} failed with HRESULT 0x{:08X}: {}",
+            method,
+            e.code().0 as u32,
+            e.message()
+        ),
+    );
+}
+
+#[cfg(feature = "eventlog")]
+fn write_event(event_type: windows::Win32::System::EventLog::REPORT_EVENT_TYPE, message: &str) {
+    use windows::{
+        core::{w, HSTRING, PCWSTR},
+        Win32::{
+            Foundation::PSID,
+            System::EventLog::{DeregisterEventSource, RegisterEventSourceW, ReportEventW},
+        },
+    };
+
+    let message = HSTRING::from(message);
+    let strings = [PCWSTR(message.as_ptr())];
+
+    // イベントログへの記録自体の失敗は、元のエラーを優先するため無視する
+    unsafe {
+        let Ok(source) = RegisterEventSourceW(PCWSTR::null(), w!("ai_voice")) else {
+            return;
+        };
+
+        let _ = ReportEventW(
+            source,
+            event_type,
+            0,
+            0,
+            PSID::default(),
+            strings.len() as u16,
+            0,
+            Some(strings.as_ptr()),
+            None,
+        );
+
+        let _ = DeregisterEventSource(source);
+    }
+}
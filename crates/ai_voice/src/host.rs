@@ -0,0 +1,168 @@
+use std::collections::BTreeSet;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{AiVoice, AiVoiceBuilder, HostStatus, Result};
+
+/// ホスト名の比較に用いる正規化を行います。
+///
+/// NFKC 正規化で全角・半角の違いを吸収した後、小文字に変換します。
+fn normalize_host_name(name: &str) -> String {
+    name.nfkc().flat_map(char::to_lowercase).collect()
+}
+
+/// 利用可能なホスト名の中から `name` に一致するものを探します。
+///
+/// 完全に一致するホスト名を優先し、存在しない場合は両者を正規化して比較します。
+///
+/// # 戻り値
+/// 一致したホスト名。存在しない場合は正規化したホスト名のベクター
+///
+pub(crate) fn find_host_name<'a>(
+    host_names: &'a [String],
+    name: &str,
+) -> Result<&'a String, Vec<String>> {
+    if let Some(host_name) = host_names.iter().find(|host_name| *host_name == name) {
+        return Ok(host_name);
+    }
+
+    let normalized = normalize_host_name(name);
+    host_names
+        .iter()
+        .find(|host_name| normalize_host_name(host_name) == normalized)
+        .ok_or_else(|| {
+            host_names
+                .iter()
+                .map(|host_name| normalize_host_name(host_name))
+                .collect()
+        })
+}
+
+#[derive(Debug, Default, PartialEq)]
+#[doc = "2つのホストプログラムで利用できるボイスの差分"]
+pub struct VoiceInventoryDiff {
+    #[doc = "1つ目のホストプログラムのみで利用できるボイス名のリスト"]
+    pub only_a: Vec<String>,
+    #[doc = "2つ目のホストプログラムのみで利用できるボイス名のリスト"]
+    pub only_b: Vec<String>,
+    #[doc = "両方のホストプログラムで利用できるボイス名のリスト"]
+    pub common: Vec<String>,
+}
+
+impl VoiceInventoryDiff {
+    /// 2つのボイス名の集合から差分を求めます。
+    ///
+    /// 各リストはボイス名の昇順に並べられ、重複は取り除かれます。
+    ///
+    /// # 引数
+    /// * `a` - 1つ目のホストプログラムのボイス名のリスト
+    /// * `b` - 2つ目のホストプログラムのボイス名のリスト
+    ///
+    pub fn new(a: &[String], b: &[String]) -> Self {
+        let a = a.iter().collect::<BTreeSet<_>>();
+        let b = b.iter().collect::<BTreeSet<_>>();
+
+        VoiceInventoryDiff {
+            only_a: a.difference(&b).map(|s| s.to_string()).collect(),
+            only_b: b.difference(&a).map(|s| s.to_string()).collect(),
+            common: a.intersection(&b).map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl AiVoice {
+    /// 2つのホストプログラムで利用できるボイスを比較します。
+    ///
+    /// 各ホストプログラムに対して順に `AiVoice` を生成して接続し、`voice_names` を取得します。
+    ///
+    /// # 引数
+    /// * `host_a` - 1つ目のホストプログラムの名前
+    /// * `host_b` - 2つ目のホストプログラムの名前
+    ///
+    /// # 戻り値
+    /// `VoiceInventoryDiff` 構造体で表される差分
+    ///
+    /// # 注意
+    /// - ホストプログラムが起動していない場合は起動して起動完了まで待機するため、
+    ///   1つのホストプログラムにつき数秒から数十秒かかることがあります。
+    /// - このメソッドが起動したホストプログラムは、ボイス名の取得後に終了されます。
+    ///   起動完了の待機や接続に失敗した場合も同様です。
+    ///   既に起動していたホストプログラムはそのままです。
+    /// - ホスト名の扱いは `AiVoiceBuilder::host_name` と同じです。
+    ///
+    pub fn compare_host_voices(host_a: &str, host_b: &str) -> Result<VoiceInventoryDiff> {
+        let a = host_voice_names(host_a)?;
+        let b = host_voice_names(host_b)?;
+
+        Ok(VoiceInventoryDiff::new(&a, &b))
+    }
+}
+
+/// 指定されたホストプログラムに接続してボイス名を取得します。
+///
+/// このメソッドがホストプログラムを起動した場合は、取得に失敗した場合も含めて終了します。
+fn host_voice_names(host_name: &str) -> Result<Vec<String>> {
+    let ai_voice = AiVoiceBuilder::new().host_name(host_name).build()?;
+    let was_running = ai_voice.status()? != HostStatus::NotRunning;
+
+    let voice_names = ai_voice
+        .ensure_ready()
+        .and_then(|()| ai_voice.voice_names());
+
+    if !was_running {
+        match voice_names {
+            Ok(_) => ai_voice.terminate_host()?,
+            // 起動や接続に失敗した場合も、起動したホストプログラムを残さない
+            Err(_) => {
+                let _ = ai_voice.terminate_host();
+            }
+        }
+    }
+
+    voice_names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_host_name_ignores_width_and_case() {
+        let host_names = vec!["A.I.VOICE Editor".to_string(), "VOICEROID2".to_string()];
+
+        assert_eq!(
+            find_host_name(&host_names, "ａ．ｉ．ｖｏｉｃｅ　ｅｄｉｔｏｒ"),
+            Ok(&host_names[0])
+        );
+        assert_eq!(
+            find_host_name(&host_names, "voiceroid2"),
+            Ok(&host_names[1])
+        );
+    }
+
+    #[test]
+    fn find_host_name_prefers_exact_match() {
+        let host_names = vec!["Editor".to_string(), "EDITOR".to_string()];
+        assert_eq!(find_host_name(&host_names, "EDITOR"), Ok(&host_names[1]));
+    }
+
+    #[test]
+    fn find_host_name_reports_normalized_candidates() {
+        let host_names = vec!["Ａ.I.VOICE Editor".to_string()];
+        assert_eq!(
+            find_host_name(&host_names, "VOICEPEAK"),
+            Err(vec!["a.i.voice editor".to_string()])
+        );
+    }
+
+    #[test]
+    fn voice_inventory_diff_splits_sets() {
+        let a = ["kotonoha_akane", "kotonoha_aoi", "yuzuki_yukari"].map(String::from);
+        let b = ["yuzuki_yukari", "kotonoha_akane", "tsurumaki_maki"].map(String::from);
+
+        let diff = VoiceInventoryDiff::new(&a, &b);
+        assert_eq!(diff.only_a, vec!["kotonoha_aoi"]);
+        assert_eq!(diff.only_b, vec!["tsurumaki_maki"]);
+        assert_eq!(diff.common, vec!["kotonoha_akane", "yuzuki_yukari"]);
+    }
+}
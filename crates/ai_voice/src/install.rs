@@ -0,0 +1,133 @@
+use std::{fmt, path::PathBuf};
+
+use windows::{
+    core::{HSTRING, PCWSTR},
+    Win32::System::Registry::{RegGetValueW, HKEY_CLASSES_ROOT, RRF_RT_REG_SZ},
+};
+
+use ai_voice_sys::TtsControl;
+
+use crate::{AiVoice, Result, Version};
+
+/// `AiVoice::install_info` で取得するインストール環境の情報
+///
+/// `Display` で不具合報告に貼り付けやすい形式の複数行の文字列を出力します。
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstallInfo {
+    #[doc = "ホストプログラムのバージョン"]
+    pub version: Version,
+    #[doc = "インストール先のディレクトリ。レジストリから取得できなかった場合は `None`"]
+    pub install_dir: Option<PathBuf>,
+    #[doc = "利用可能なボイス名のリスト"]
+    pub voice_names: Vec<String>,
+    #[doc = "登録されているボイスプリセットの数"]
+    pub voice_preset_count: usize,
+}
+
+impl fmt::Display for InstallInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "version: {}", self.version)?;
+        match &self.install_dir {
+            Some(install_dir) => writeln!(f, "install_dir: {}", install_dir.display())?,
+            None => writeln!(f, "install_dir: (unknown)")?,
+        }
+        writeln!(f, "voices ({}):", self.voice_names.len())?;
+        for voice_name in &self.voice_names {
+            writeln!(f, "  - {}", voice_name)?;
+        }
+        write!(f, "voice_presets: {}", self.voice_preset_count)
+    }
+}
+
+/// `file:///C:/...` 形式の URI をパスに変換します。
+fn path_from_code_base(code_base: &str) -> Option<PathBuf> {
+    let path = code_base
+        .strip_prefix("file:///")
+        .or_else(|| code_base.strip_prefix("file://"))?;
+    Some(PathBuf::from(path.replace('/', "\\")))
+}
+
+/// `TtsControl` の COM 登録情報からインストール先のディレクトリを取得します。
+///
+/// .NET の COM サーバーとして登録された `InprocServer32` の `CodeBase` が指すアセンブリの
+/// ディレクトリをインストール先とみなします。
+///
+fn install_dir_from_registry() -> Option<PathBuf> {
+    let sub_key = HSTRING::from(format!("CLSID\\{{{:?}}}\\InprocServer32", TtsControl));
+    let value = HSTRING::from("CodeBase");
+
+    let mut buffer = vec![0u16; 1024];
+    let mut size = (buffer.len() * 2) as u32;
+    unsafe {
+        RegGetValueW(
+            HKEY_CLASSES_ROOT,
+            PCWSTR(sub_key.as_ptr()),
+            PCWSTR(value.as_ptr()),
+            RRF_RT_REG_SZ,
+            None,
+            Some(buffer.as_mut_ptr().cast()),
+            Some(&mut size),
+        )
+        .ok()
+        .ok()?;
+    }
+
+    // 取得したサイズは終端の NUL を含むバイト数
+    let len = (size as usize / 2).saturating_sub(1);
+    let code_base = String::from_utf16(&buffer[..len]).ok()?;
+    path_from_code_base(&code_base)?.parent().map(PathBuf::from)
+}
+
+impl AiVoice {
+    /// 不具合報告などに使用するインストール環境の情報を取得します。
+    ///
+    /// # 戻り値
+    /// ホストプログラムのバージョン、インストール先、ボイスとボイスプリセットの情報をまとめた `InstallInfo`
+    ///
+    /// # 注意
+    /// - ボイスとボイスプリセットの情報を取得するため、ホストプログラムと接続済みである必要があります。
+    /// - インストール先はレジストリに登録された `TtsControl` の情報から推定します。
+    ///   取得できない場合もエラーにはならず、`install_dir` が `None` になります。
+    ///
+    pub fn install_info(&self) -> Result<InstallInfo> {
+        Ok(InstallInfo {
+            version: self.version_parsed()?,
+            install_dir: install_dir_from_registry(),
+            voice_names: self.voice_names()?,
+            voice_preset_count: self.voice_preset_names()?.len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_from_code_base_converts_file_uri() {
+        assert_eq!(
+            path_from_code_base("file:///C:/Program Files/AI/AIVoice/AI.Talk.Editor.Api.DLL"),
+            Some(PathBuf::from(
+                "C:\\Program Files\\AI\\AIVoice\\AI.Talk.Editor.Api.DLL"
+            ))
+        );
+        assert_eq!(path_from_code_base("C:\\AI.Talk.Editor.Api.DLL"), None);
+    }
+
+    #[test]
+    fn install_info_reports_environment() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        let install_info = ai_voice.install_info()?;
+        assert!(!install_info.voice_names.is_empty());
+
+        let report = install_info.to_string();
+        assert!(report.contains("version:"));
+        assert!(report.contains("voice_presets:"));
+
+        Ok(())
+    }
+}
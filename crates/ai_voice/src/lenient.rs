@@ -0,0 +1,268 @@
+use serde::de::DeserializeOwned;
+
+use crate::{AiVoiceError, MasterControl, MergedVoiceContainer, Result, Style, VoicePreset};
+
+/// 寛容な解析の結果
+///
+/// JSON の解析に失敗した場合でも、認識できた項目から組み立てた値を保持します。
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct LenientParse<T> {
+    #[doc = "解析した値"]
+    pub value: T,
+    #[doc = "取得できずに既定値で補った項目の JSON 上の名前"]
+    pub missing_fields: Vec<&'static str>,
+}
+
+impl<T> LenientParse<T> {
+    /// 一部の項目を既定値で補った部分的な結果かどうかを取得します。
+    ///
+    pub fn is_partial(&self) -> bool {
+        !self.missing_fields.is_empty()
+    }
+}
+
+/// `"key":` の直後の値の開始位置からの文字列を取得します。
+fn value_after<'a>(json: &'a str, key: &str) -> Option<&'a str> {
+    let pattern = format!("\"{}\"", key);
+    let rest = &json[json.find(&pattern)? + pattern.len()..];
+    Some(rest.trim_start().strip_prefix(':')?.trim_start())
+}
+
+fn extract_number(json: &str, key: &str) -> Option<f64> {
+    let value = value_after(json, key)?;
+    let end = value
+        .find(|c: char| !matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E'))
+        .unwrap_or(value.len());
+    value[..end].parse().ok()
+}
+
+fn extract_string(json: &str, key: &str) -> Option<String> {
+    let value = value_after(json, key)?.strip_prefix('"')?;
+
+    let mut escaped = false;
+    let end = value.char_indices().find_map(|(i, c)| match c {
+        _ if escaped => {
+            escaped = false;
+            None
+        }
+        '\\' => {
+            escaped = true;
+            None
+        }
+        '"' => Some(i),
+        _ => None,
+    })?;
+
+    serde_json::from_str(&format!("\"{}\"", &value[..end])).ok()
+}
+
+/// `open` で始まり対応する `close` で終わる値を解析します。
+fn extract_balanced<T: DeserializeOwned>(
+    json: &str,
+    key: &str,
+    open: char,
+    close: char,
+) -> Option<T> {
+    let value = value_after(json, key)?;
+    if !value.starts_with(open) {
+        return None;
+    }
+
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in value.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            _ if in_string => {}
+            c if c == open => depth += 1,
+            c if c == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return serde_json::from_str(&value[..=i]).ok();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// 項目を取得し、取得できない場合は既定値を使用して `missing` に記録します。
+fn field<T>(missing: &mut Vec<&'static str>, key: &'static str, value: Option<T>, default: T) -> T {
+    value.unwrap_or_else(|| {
+        missing.push(key);
+        default
+    })
+}
+
+fn number<T: TryFrom<u32>>(json: &str, key: &str) -> Option<T> {
+    extract_number(json, key)
+        .filter(|n| n.fract() == 0.0 && *n >= 0.0 && *n <= u32::MAX as f64)
+        .and_then(|n| T::try_from(n as u32).ok())
+}
+
+/// マスターコントロールの JSON を寛容に解析します。
+///
+/// 厳密な解析に失敗した場合は各項目を個別に探し、取得できない項目には標準値を使用します。
+///
+pub(crate) fn parse_master_control(json: &str) -> LenientParse<MasterControl> {
+    if let Ok(value) = serde_json::from_str(json) {
+        return LenientParse {
+            value,
+            missing_fields: Vec::new(),
+        };
+    }
+
+    let mut missing = Vec::new();
+    let float = |key| extract_number(json, key).map(|n| n as f32);
+    let value = MasterControl {
+        volume: field(&mut missing, "Volume", float("Volume"), 1.0),
+        speed: field(&mut missing, "Speed", float("Speed"), 1.0),
+        pitch: field(&mut missing, "Pitch", float("Pitch"), 1.0),
+        pitch_range: field(&mut missing, "PitchRange", float("PitchRange"), 1.0),
+        middle_pause: field(
+            &mut missing,
+            "MiddlePause",
+            number(json, "MiddlePause"),
+            150,
+        ),
+        long_pause: field(&mut missing, "LongPause", number(json, "LongPause"), 370),
+        sentence_pause: field(
+            &mut missing,
+            "SentencePause",
+            number(json, "SentencePause"),
+            800,
+        ),
+    };
+
+    LenientParse {
+        value,
+        missing_fields: missing,
+    }
+}
+
+/// ボイスプリセットの JSON を寛容に解析します。
+///
+/// 厳密な解析に失敗した場合は各項目を個別に探し、取得できない項目には
+/// `VoicePreset::from_voice` と同じ値を使用します。
+///
+/// # エラー
+/// ボイス名を取得できない場合にエラーを返します。
+///
+pub(crate) fn parse_voice_preset(json: &str) -> Result<LenientParse<VoicePreset>> {
+    if let Ok(value) = VoicePreset::from_json(json) {
+        return Ok(LenientParse {
+            value,
+            missing_fields: Vec::new(),
+        });
+    }
+
+    let voice_name =
+        extract_string(json, "VoiceName").ok_or(AiVoiceError::UnrecognizableVoicePreset)?;
+    let default = VoicePreset::from_voice(&voice_name);
+
+    let mut missing = Vec::new();
+    let float = |key| extract_number(json, key).map(|n| n as f32);
+    let value = VoicePreset {
+        preset_name: field(
+            &mut missing,
+            "PresetName",
+            extract_string(json, "PresetName"),
+            default.preset_name,
+        ),
+        voice_name,
+        volume: field(&mut missing, "Volume", float("Volume"), default.volume),
+        speed: field(&mut missing, "Speed", float("Speed"), default.speed),
+        pitch: field(&mut missing, "Pitch", float("Pitch"), default.pitch),
+        pitch_range: field(
+            &mut missing,
+            "PitchRange",
+            float("PitchRange"),
+            default.pitch_range,
+        ),
+        middle_pause: field(
+            &mut missing,
+            "MiddlePause",
+            number(json, "MiddlePause"),
+            default.middle_pause,
+        ),
+        long_pause: field(
+            &mut missing,
+            "LongPause",
+            number(json, "LongPause"),
+            default.long_pause,
+        ),
+        styles: field(
+            &mut missing,
+            "Styles",
+            extract_balanced::<Vec<Style>>(json, "Styles", '[', ']'),
+            default.styles,
+        ),
+        merged_voice_container: field(
+            &mut missing,
+            "MergedVoiceContainer",
+            extract_balanced::<MergedVoiceContainer>(json, "MergedVoiceContainer", '{', '}'),
+            default.merged_voice_container,
+        ),
+    };
+
+    Ok(LenientParse {
+        value,
+        missing_fields: missing,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRESET: &str = r#"{"PresetName":"琴葉 茜","VoiceName":"akane_west_emo_44","Volume":1.2,"Speed":0.9,"Pitch":1.0,"PitchRange":1.1,"MiddlePause":150,"LongPause":370,"Styles":[{"Name":"J","Value":0.5}],"MergedVoiceContainer":{"BasePitchVoiceName":"akane_west_emo_44","MergedVoices":[]}}"#;
+
+    #[test]
+    fn well_formed_json_is_not_partial() -> Result<()> {
+        let parsed = parse_voice_preset(PRESET)?;
+        assert!(!parsed.is_partial());
+        assert_eq!(parsed.value.preset_name, "琴葉 茜");
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncated_preset_keeps_recognizable_fields() -> Result<()> {
+        let truncated = &PRESET[..PRESET.find("\"MergedVoiceContainer\"").unwrap() + 30];
+        let parsed = parse_voice_preset(truncated)?;
+
+        assert_eq!(parsed.missing_fields, ["MergedVoiceContainer"]);
+        assert_eq!(parsed.value.volume, 1.2);
+        assert_eq!(parsed.value.pitch_range, 1.1);
+        assert_eq!(parsed.value.styles.len(), 1);
+        assert_eq!(
+            parsed.value.merged_voice_container.base_pitch_voice_name,
+            "akane_west_emo_44"
+        );
+
+        assert!(parse_voice_preset(r#"{"PresetName":"a","Volume":1.0"#).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn malformed_master_control_fills_defaults() {
+        let parsed =
+            parse_master_control(r#"{"Volume":2.0,"Speed":1.5,"Pitch":,"MiddlePause":200"#);
+
+        assert_eq!(
+            parsed.missing_fields,
+            ["Pitch", "PitchRange", "LongPause", "SentencePause"]
+        );
+        assert_eq!(parsed.value.volume, 2.0);
+        assert_eq!(parsed.value.speed, 1.5);
+        assert_eq!(parsed.value.pitch, 1.0);
+        assert_eq!(parsed.value.middle_pause, 200);
+    }
+}
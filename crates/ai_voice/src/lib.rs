@@ -1,15 +1,89 @@
 pub use ai_voice::*;
+pub use builder::*;
+pub use edit_session::*;
+pub use error::*;
+pub use host::*;
+pub use install::*;
+pub use lenient::*;
+pub use limits::*;
+pub use list::*;
+pub use master_control::*;
+pub use pause::*;
+#[cfg(feature = "tokio")]
+pub use play_future::*;
+pub use play_queue::*;
+pub use preset::*;
+pub use redact::*;
+pub use retry::*;
+pub use shared::*;
+pub use text::*;
+pub use timing::*;
+pub use version::*;
+pub use watcher::*;
+
+pub use ai_voice_sys::ITtsControl;
+
+pub mod prelude;
+
+#[macro_use]
+mod macros;
 
 mod ai_voice;
+#[cfg(feature = "tokio")]
+mod async_ready;
+mod audio;
+mod backup;
+mod builder;
+mod csv;
+mod edit_session;
+mod error;
+mod eventlog;
+mod host;
+mod install;
+mod lenient;
+mod limits;
+mod list;
+mod master_control;
+mod pause;
+#[cfg(feature = "tokio")]
+mod play_future;
+mod play_queue;
+mod preset;
+mod process;
+mod redact;
+mod retry;
+mod shared;
+mod stall;
+#[cfg(test)]
+pub(crate) mod test_fixtures;
+mod text;
+mod timing;
+mod version;
+mod watcher;
 
 #[cfg(test)]
 mod tests {
-    use std::hint;
+    use std::{hint, thread, time::Duration};
 
     use anyhow::Result;
+    use windows::Win32::{
+        Foundation::S_FALSE,
+        System::Com::{CoInitializeEx, CoUninitialize, COINIT_MULTITHREADED},
+    };
 
     use super::*;
 
+    /// 現在のスレッドで COM が初期化されたままかどうかを調べます。
+    fn com_initialized_on_this_thread() -> bool {
+        unsafe {
+            let hr = CoInitializeEx(None, COINIT_MULTITHREADED);
+            if hr.is_ok() {
+                CoUninitialize();
+            }
+            hr == S_FALSE
+        }
+    }
+
     #[test]
     fn main() -> Result<()> {
         let ai_voice = AiVoice::new()?;
@@ -40,4 +114,278 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn clone_shares_control() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        let cloned = ai_voice.clone();
+        ai_voice.set_text_force("こんにちは")?;
+        assert_eq!(cloned.text()?, "こんにちは");
+
+        drop(cloned);
+        drop(ai_voice);
+
+        Ok(())
+    }
+
+    #[test]
+    fn clones_share_last_activity() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        let cloned = ai_voice.clone();
+        thread::sleep(Duration::from_millis(500));
+        ai_voice.status()?;
+
+        assert!(cloned.time_since_last_activity() < Duration::from_millis(500));
+        assert_eq!(cloned.last_activity(), ai_voice.last_activity());
+
+        Ok(())
+    }
+
+    #[test]
+    fn ensure_playing_and_stopped_are_idempotent() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        ai_voice.set_text_edit_mode(TextEditMode::Text)?;
+        ai_voice.set_text_force("こんにちは。今日はいい天気ですね。")?;
+
+        // アイドル状態からの停止は何もしない
+        ai_voice.ensure_stopped()?;
+        assert_eq!(ai_voice.status()?, HostStatus::Idle);
+
+        // アイドル状態からは再生を開始する
+        ai_voice.ensure_playing()?;
+        while ai_voice.status()? != HostStatus::Busy {
+            hint::spin_loop();
+        }
+
+        // 再生中に呼び出しても一時停止しない
+        ai_voice.ensure_playing()?;
+        assert_eq!(ai_voice.status()?, HostStatus::Busy);
+
+        // 再生中からは停止する
+        ai_voice.ensure_stopped()?;
+        while ai_voice.status()? != HostStatus::Idle {
+            hint::spin_loop();
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn reload_word_dictionary_probe_without_changes() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        // 辞書を変更していないため、再生時間は変化しない
+        assert!(!ai_voice.reload_word_dictionary_probe("こんにちは")?);
+        assert_eq!(ai_voice.text()?, "こんにちは");
+        assert!(ai_voice.reload_word_dictionary_probe("").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_prints_summary_without_host() -> Result<()> {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Wrapper {
+            ai_voice: AiVoice,
+        }
+
+        let ai_voice = AiVoice::new()?;
+        let output = format!("{:?}", Wrapper { ai_voice });
+        assert!(output.contains("session_id"));
+        assert!(output.contains("ever_connected: false"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn content_fingerprint_tracks_content() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        ai_voice.set_text_edit_mode(TextEditMode::Text)?;
+
+        ai_voice.set_text_force("こんにちは")?;
+        let first = ai_voice.content_fingerprint()?;
+        assert_eq!(ai_voice.content_fingerprint()?, first);
+
+        ai_voice.set_text_force("こんばんは")?;
+        assert_ne!(ai_voice.content_fingerprint()?, first);
+
+        ai_voice.set_text_edit_mode(TextEditMode::List)?;
+        assert_ne!(ai_voice.content_fingerprint()?, first);
+
+        Ok(())
+    }
+
+    #[test]
+    fn content_fingerprint_with_rows_matches_in_text_mode() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        ai_voice.set_text_edit_mode(TextEditMode::Text)?;
+
+        ai_voice.set_text_force("こんにちは")?;
+        assert_eq!(
+            ai_voice.content_fingerprint_with_rows()?,
+            ai_voice.content_fingerprint()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reset_rewinds_to_start() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        ai_voice.set_text_edit_mode(TextEditMode::Text)?;
+        ai_voice.set_text_force("こんにちは。今日はいい天気ですね。明日も晴れるそうです。")?;
+
+        ai_voice.play()?;
+        thread::sleep(Duration::from_millis(1000));
+        ai_voice.reset()?;
+
+        while ai_voice.status()? != HostStatus::Idle {
+            hint::spin_loop();
+        }
+        assert_eq!(ai_voice.text_selection_start()?, 0);
+        assert_eq!(ai_voice.text_selection_length()?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn play_and_wait_returns_after_playback() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        ai_voice.set_text_edit_mode(TextEditMode::Text)?;
+
+        ai_voice.set_text("こんにちは")?;
+        ai_voice.play_and_wait()?;
+        assert_eq!(ai_voice.status()?, HostStatus::Idle);
+
+        // 空のテキストでは処理中にならないが、待機せずに終了する
+        ai_voice.set_text("")?;
+        ai_voice.play_and_wait_timeout(Duration::from_secs(5))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn play_and_wait_timeout_reports_timeout() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        ai_voice.set_text_edit_mode(TextEditMode::Text)?;
+        ai_voice.set_text("こんにちは。今日はいい天気ですね。明日も晴れるそうです。")?;
+
+        let e = ai_voice
+            .play_and_wait_timeout(Duration::from_millis(300))
+            .unwrap_err();
+        assert!(matches!(&e, AiVoiceError::PlaybackTimeout { .. }));
+        ai_voice.stop()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn two_instances_drop_in_either_order() {
+        for reverse in [false, true] {
+            thread::spawn(move || -> Result<()> {
+                let a = AiVoice::new()?;
+                let b = AiVoice::new()?;
+
+                let (first, second) = if reverse { (b, a) } else { (a, b) };
+                drop(first);
+                second.status()?;
+                drop(second);
+
+                assert!(!com_initialized_on_this_thread());
+                Ok(())
+            })
+            .join()
+            .unwrap()
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn clones_dropped_before_original() {
+        thread::spawn(|| -> Result<()> {
+            let ai_voice = AiVoice::new()?;
+            let clones = (0..3).map(|_| ai_voice.clone()).collect::<Vec<_>>();
+
+            drop(clones);
+            ai_voice.status()?;
+            assert!(com_initialized_on_this_thread());
+
+            drop(ai_voice);
+            assert!(!com_initialized_on_this_thread());
+            Ok(())
+        })
+        .join()
+        .unwrap()
+        .unwrap();
+    }
+
+    #[test]
+    fn original_answers_after_clone_is_dropped() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        let cloned = ai_voice.clone();
+        drop(cloned);
+        assert!(!ai_voice.version()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn leak_com_keeps_com_initialized_after_drop() {
+        thread::spawn(|| -> Result<()> {
+            let ai_voice = AiVoiceBuilder::new().leak_com(true).build()?;
+            ai_voice.status()?;
+            drop(ai_voice);
+
+            assert!(com_initialized_on_this_thread());
+            Ok(())
+        })
+        .join()
+        .unwrap()
+        .unwrap();
+    }
+
+    #[test]
+    fn drop_on_another_thread_keeps_this_thread_usable() -> Result<()> {
+        // `AiVoice` は `Send` ではないため、生成したスレッド以外で破棄することはできない。
+        // 別スレッドで生成・破棄しても、このスレッドのインスタンスに影響しないことを確認する。
+        let ai_voice = AiVoice::new()?;
+
+        thread::spawn(|| -> Result<()> {
+            let other = AiVoice::new()?;
+            other.status()?;
+            drop(other);
+
+            assert!(!com_initialized_on_this_thread());
+            Ok(())
+        })
+        .join()
+        .unwrap()?;
+
+        ai_voice.status()?;
+        Ok(())
+    }
 }
@@ -0,0 +1,127 @@
+use std::fmt;
+
+use crate::AiVoiceError;
+
+/// テキスト形式で設定できるテキストの最大の長さ（UTF-16 のコード単位数）の既定値
+///
+/// ホストプログラムの仕様として公開されている値ではなく、根拠となる資料もない暫定的な目安です。
+/// 実際の上限はホストプログラムのバージョンや環境によって異なる場合があるため、
+/// 必要に応じて `AiVoiceBuilder::content_limits` で変更してください。
+///
+pub const MAX_TEXT_UTF16_LEN: usize = 20_000;
+
+/// リスト形式に設定できる最大の行数の既定値
+///
+/// `MAX_TEXT_UTF16_LEN` と同じく暫定的な目安です。
+///
+pub const MAX_LIST_ROWS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[doc = "`AiVoiceBuilder::check_content_size` で確認する大きさの上限"]
+pub struct ContentLimits {
+    #[doc = "テキスト形式のテキストの最大の長さ（UTF-16 のコード単位数）"]
+    pub max_text_utf16_len: usize,
+    #[doc = "リスト形式の最大の行数"]
+    pub max_list_rows: usize,
+}
+
+impl Default for ContentLimits {
+    fn default() -> Self {
+        ContentLimits {
+            max_text_utf16_len: MAX_TEXT_UTF16_LEN,
+            max_list_rows: MAX_LIST_ROWS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[doc = "大きさが制限される内容の種類"]
+pub enum ContentKind {
+    #[doc = "テキスト形式のテキストの長さ（UTF-16 のコード単位数）"]
+    TextLength,
+    #[doc = "リスト形式の行数"]
+    ListRows,
+}
+
+impl fmt::Display for ContentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ContentKind::TextLength => "text length (UTF-16 code units)",
+            ContentKind::ListRows => "list row count",
+        })
+    }
+}
+
+/// テキストの長さが `limits.max_text_utf16_len` 以下であることを確認します。
+pub(crate) fn check_text_len(text: &str, limits: &ContentLimits) -> Result<(), AiVoiceError> {
+    check(
+        ContentKind::TextLength,
+        text.encode_utf16().count(),
+        limits.max_text_utf16_len,
+    )
+}
+
+/// リスト形式の行数が `limits.max_list_rows` 以下であることを確認します。
+pub(crate) fn check_list_rows(rows: usize, limits: &ContentLimits) -> Result<(), AiVoiceError> {
+    check(ContentKind::ListRows, rows, limits.max_list_rows)
+}
+
+fn check(kind: ContentKind, size: usize, limit: usize) -> Result<(), AiVoiceError> {
+    if size > limit {
+        return Err(AiVoiceError::ContentTooLarge { kind, size, limit });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_length_is_counted_in_utf16_units() {
+        let limits = ContentLimits::default();
+        assert!(check_text_len(&"あ".repeat(MAX_TEXT_UTF16_LEN), &limits).is_ok());
+        // サロゲートペアは2単位として数える
+        assert!(matches!(
+            check_text_len(&"😀".repeat(MAX_TEXT_UTF16_LEN / 2 + 1), &limits),
+            Err(AiVoiceError::ContentTooLarge {
+                kind: ContentKind::TextLength,
+                size,
+                limit: MAX_TEXT_UTF16_LEN,
+            }) if size == MAX_TEXT_UTF16_LEN + 2
+        ));
+    }
+
+    #[test]
+    fn list_rows_are_limited() {
+        let limits = ContentLimits::default();
+        assert!(check_list_rows(MAX_LIST_ROWS, &limits).is_ok());
+        assert!(matches!(
+            check_list_rows(MAX_LIST_ROWS + 1, &limits),
+            Err(AiVoiceError::ContentTooLarge {
+                kind: ContentKind::ListRows,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn custom_limits_are_used() {
+        let limits = ContentLimits {
+            max_text_utf16_len: 3,
+            max_list_rows: 2,
+        };
+        assert!(check_text_len("あいう", &limits).is_ok());
+        assert!(matches!(
+            check_text_len("あいうえ", &limits),
+            Err(AiVoiceError::ContentTooLarge {
+                size: 4,
+                limit: 3,
+                ..
+            })
+        ));
+        assert!(check_list_rows(2, &limits).is_ok());
+        assert!(check_list_rows(3, &limits).is_err());
+    }
+}
@@ -0,0 +1,429 @@
+use std::{fs, ops::Range, path::Path, time::Duration};
+
+use crate::{AiVoice, AiVoiceError, Result, TextEditMode};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[doc = "リスト形式の1行分の項目"]
+pub struct ListItem {
+    #[doc = "ボイスプリセット名"]
+    pub voice_preset_name: String,
+    #[doc = "テキスト"]
+    pub text: String,
+}
+
+impl ListItem {
+    /// `ListItem` を作成します。
+    ///
+    /// # 引数
+    /// * `voice_preset_name` - ボイスプリセット名
+    /// * `text` - テキスト
+    ///
+    pub fn new(voice_preset_name: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            voice_preset_name: voice_preset_name.into(),
+            text: text.into(),
+        }
+    }
+}
+
+impl From<(String, String)> for ListItem {
+    fn from((voice_preset_name, text): (String, String)) -> Self {
+        Self {
+            voice_preset_name,
+            text,
+        }
+    }
+}
+
+/// `start` から `length` 行の範囲がリスト形式の行数 `count` に収まるかどうかを検証します。
+pub(crate) fn check_list_range(start: i32, length: i32, count: i32) -> Result<(), AiVoiceError> {
+    if start < 0 || length < 0 || start.saturating_add(length) > count {
+        return Err(AiVoiceError::IndexOutOfRange {
+            start,
+            length,
+            count,
+        });
+    }
+
+    Ok(())
+}
+
+/// `start` から `count` 行の範囲を、`total` 行のリストに収まるように制限します。
+///
+/// 負の値は `0` として扱われます。
+fn clamp_window(start: i32, count: i32, total: i32) -> Range<i32> {
+    let start = start.clamp(0, total.max(0));
+    let end = start.saturating_add(count.max(0)).min(total.max(0));
+    start..end
+}
+
+/// インデックスが連続している場合に、その範囲を取得します。
+///
+/// インデックスは昇順に並べ替えてから判定されます。空の場合と連続していない場合は `None` を返します。
+fn contiguous_range(indices: &[i32]) -> Option<Range<i32>> {
+    let mut indices = indices.to_vec();
+    indices.sort_unstable();
+    indices.dedup();
+
+    let (&first, &last) = (indices.first()?, indices.last()?);
+    (last - first + 1 == indices.len() as i32).then_some(first..last + 1)
+}
+
+/// リスト形式に読み込むスクリプトを解析します。
+///
+/// 1行が1行分のリスト項目になり、`ボイスプリセット名:テキスト` の形式で
+/// 行ごとにボイスプリセットを指定できます。
+/// `:` より前が `known_presets` に含まれない場合は、行全体がテキストとして扱われます。
+/// 空行は無視され、各行の末尾の空白は取り除かれます。
+///
+/// # 戻り値
+/// `(ボイスプリセット名, テキスト)` のベクター
+///
+fn parse_list_script(
+    content: &str,
+    default_preset: &str,
+    known_presets: &[String],
+) -> Vec<(String, String)> {
+    content
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| match line.split_once(':') {
+            Some((preset, text)) if known_presets.iter().any(|p| p == preset) => {
+                (preset.to_string(), text.to_string())
+            }
+            _ => (default_preset.to_string(), line.to_string()),
+        })
+        .collect()
+}
+
+impl AiVoice {
+    /// テキストファイルの内容でリスト形式の行を置き換えます。
+    ///
+    /// ファイルの1行が1行分のリスト項目になります。
+    /// `ボイスプリセット名:テキスト` の形式で行ごとにボイスプリセットを指定でき、
+    /// 指定がない行には `default_preset` が使用されます。
+    ///
+    /// # 引数
+    /// * `path` - 読み込むファイルのパス
+    /// * `default_preset` - ボイスプリセットの指定がない行に使用するボイスプリセット名
+    ///
+    /// # 戻り値
+    /// 追加した行数
+    ///
+    /// # 注意
+    /// - 既存のリスト形式の行はすべて削除されます。
+    /// - 空行は無視され、各行の末尾の空白は取り除かれます。
+    /// - `:` より前が登録されているボイスプリセット名と一致しない場合、行全体がテキストとして扱われます。
+    ///
+    pub fn load_list_from_file(
+        &self,
+        path: impl AsRef<Path>,
+        default_preset: &str,
+    ) -> Result<usize> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(AiVoiceError::file(path))?;
+
+        let items = parse_list_script(&content, default_preset, &self.voice_preset_names()?);
+
+        let items = items.into_iter().map(ListItem::from).collect::<Vec<_>>();
+        self.replace_list(&items)?;
+
+        Ok(items.len())
+    }
+
+    /// リスト形式で選択されている行が連続している場合に、その範囲を取得します。
+    ///
+    /// # 戻り値
+    /// 選択行が連続している場合は選択範囲（0スタート）、
+    /// 選択行が連続していない場合や選択されていない場合は `None`
+    ///
+    /// # 注意
+    /// 戻り値の範囲は `set_list_selection_range(range.start, range.len() as i32)` で再び選択できます。
+    ///
+    pub fn list_selection_as_range(&self) -> Result<Option<Range<i32>>> {
+        Ok(contiguous_range(&self.list_selection_indices()?))
+    }
+
+    /// リスト形式の全行のボイスプリセット名とテキストを取得します。
+    ///
+    /// # 戻り値
+    /// `ListItem` 構造体のベクター
+    ///
+    /// # 注意
+    /// 各行を順に単一選択して取得するため、処理中は選択状態が変化します。
+    /// 処理後は元の選択状態に戻されます。
+    ///
+    pub fn list_items(&self) -> Result<Vec<ListItem>> {
+        self.list_items_range(0, self.list_count()?)
+    }
+
+    /// リスト形式の指定された範囲の行のボイスプリセット名とテキストを取得します。
+    ///
+    /// 表示されている行のみを読み込む場合など、全行を取得せずに一部の行を取得するために使用します。
+    ///
+    /// # 引数
+    /// * `start` - 開始行のインデックス
+    /// * `count` - 取得する行数
+    ///
+    /// # 戻り値
+    /// `ListItem` 構造体のベクター
+    ///
+    /// # 注意
+    /// - 範囲は `list_count` に収まるように制限されるため、末尾では `count` より少ない行が返されます。
+    ///   `start` が行数以上の場合は空のベクターを返します。負の値は `0` として扱われます。
+    /// - 各行を順に単一選択して取得するため、処理中は選択状態が変化します。
+    ///   処理後は元の選択状態に戻されます。
+    ///
+    pub fn list_items_range(&self, start: i32, count: i32) -> Result<Vec<ListItem>> {
+        let window = clamp_window(start, count, self.list_count()?);
+        if window.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let selection = self.list_selection_indices()?;
+
+        let items = window
+            .map(|index| {
+                self.set_list_selection_index(index)?;
+                Ok(ListItem::new(
+                    self.list_voice_preset()?,
+                    self.list_sentence()?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>();
+
+        self.set_list_selection_indices(selection)?;
+
+        items
+    }
+
+    /// 選択状態を変えずに、リスト形式の指定された行のテキストを取得します。
+    ///
+    /// # 引数
+    /// * `index` - 行のインデックス
+    ///
+    /// # エラー
+    /// `index` が範囲外の場合に `AiVoiceError::IndexOutOfRange` を返します。
+    ///
+    /// # 注意
+    /// 一時的に指定された行を単一選択して取得し、処理後は元の選択状態に戻されます。
+    ///
+    pub fn list_sentence_at(&self, index: i32) -> Result<String> {
+        self.with_list_selection_at(index, || self.list_sentence())
+    }
+
+    /// 選択状態を変えずに、リスト形式の指定された行のボイスプリセット名を取得します。
+    ///
+    /// # 引数
+    /// * `index` - 行のインデックス
+    ///
+    /// # エラー
+    /// `index` が範囲外の場合に `AiVoiceError::IndexOutOfRange` を返します。
+    ///
+    /// # 注意
+    /// 一時的に指定された行を単一選択して取得し、処理後は元の選択状態に戻されます。
+    ///
+    pub fn list_voice_preset_at(&self, index: i32) -> Result<String> {
+        self.with_list_selection_at(index, || self.list_voice_preset())
+    }
+
+    fn with_list_selection_at<T>(&self, index: i32, f: impl FnOnce() -> Result<T>) -> Result<T> {
+        check_list_range(index, 1, self.list_count()?)?;
+
+        let selection = self.list_selection_indices()?;
+
+        let result = self.set_list_selection_index(index).and_then(|_| f());
+
+        self.set_list_selection_indices(selection)?;
+
+        result
+    }
+
+    /// リスト形式の各行の再生時間を取得します。
+    ///
+    /// # 戻り値
+    /// 各行の再生時間のベクター
+    ///
+    /// # 注意
+    /// 各行を順に単一選択して再生時間を取得するため、処理中は選択状態が変化します。
+    /// 処理後は元の選択状態に戻されます。
+    ///
+    pub fn list_item_durations(&self) -> Result<Vec<Duration>> {
+        let indices = (0..self.list_count()?).collect::<Vec<_>>();
+        self.list_item_durations_of(&indices)
+    }
+
+    /// リスト形式で選択されている行の合計再生時間を取得します。
+    ///
+    /// # 戻り値
+    /// 再生時間
+    ///
+    /// # 注意
+    /// - リスト形式で複数行が選択されている場合、選択行ごとの再生時間の合計を返します。
+    ///   処理中は選択状態が変化しますが、処理後は元の選択状態に戻されます。
+    /// - それ以外の場合はホストプログラムが返す再生時間をそのまま返します。
+    ///
+    pub fn selected_play_time(&self) -> Result<Duration> {
+        if self.text_edit_mode()? == TextEditMode::List && self.list_selection_count()? > 1 {
+            let indices = self.list_selection_indices()?;
+            return Ok(self.list_item_durations_of(&indices)?.into_iter().sum());
+        }
+
+        Ok(Duration::from_millis(self.play_time()?.max(0) as u64))
+    }
+
+    fn list_item_durations_of(&self, indices: &[i32]) -> Result<Vec<Duration>> {
+        let selection = self.list_selection_indices()?;
+
+        let durations = indices
+            .iter()
+            .map(|&index| {
+                self.set_list_selection_index(index)?;
+                Ok(Duration::from_millis(
+                    self.play_time_uncached()?.max(0) as u64
+                ))
+            })
+            .collect::<Result<Vec<_>>>();
+
+        self.set_list_selection_indices(selection)?;
+
+        durations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_list_range_accepts_valid_ranges() {
+        assert!(check_list_range(0, 3, 3).is_ok());
+        assert!(check_list_range(2, 1, 3).is_ok());
+        assert!(check_list_range(1, 0, 3).is_ok());
+        assert!(check_list_range(3, 0, 3).is_ok());
+    }
+
+    #[test]
+    fn check_list_range_rejects_invalid_ranges() {
+        assert!(matches!(
+            check_list_range(-1, 1, 3),
+            Err(AiVoiceError::IndexOutOfRange {
+                start: -1,
+                length: 1,
+                count: 3
+            })
+        ));
+        assert!(check_list_range(0, -1, 3).is_err());
+        assert!(check_list_range(2, 2, 3).is_err());
+        assert!(check_list_range(4, 0, 3).is_err());
+        assert!(check_list_range(1, i32::MAX, 3).is_err());
+    }
+
+    #[test]
+    fn clamp_window_limits_to_list_count() {
+        assert_eq!(clamp_window(0, 10, 100), 0..10);
+        // 末尾では少なくなる
+        assert_eq!(clamp_window(95, 10, 100), 95..100);
+        assert_eq!(clamp_window(100, 10, 100), 100..100);
+        assert_eq!(clamp_window(200, 10, 100), 100..100);
+        assert_eq!(clamp_window(-5, 10, 100), 0..10);
+        assert_eq!(clamp_window(5, -1, 100), 5..5);
+        assert_eq!(clamp_window(0, i32::MAX, 3), 0..3);
+    }
+
+    #[test]
+    fn contiguous_range_detects_blocks() {
+        // 連続
+        assert_eq!(contiguous_range(&[2, 3, 4]), Some(2..5));
+        assert_eq!(contiguous_range(&[4, 2, 3]), Some(2..5));
+        // 単一
+        assert_eq!(contiguous_range(&[7]), Some(7..8));
+        // 不連続
+        assert_eq!(contiguous_range(&[0, 2, 3]), None);
+        // 空
+        assert_eq!(contiguous_range(&[]), None);
+    }
+
+    #[test]
+    fn parse_list_script_handles_prefix_and_blank_lines() {
+        let known_presets = vec!["琴葉 茜".to_string(), "琴葉 葵".to_string()];
+        let content = "こんにちは  \n\n琴葉 葵:お姉ちゃん\n   \n時刻: 10:00\n";
+
+        let items = parse_list_script(content, "琴葉 茜", &known_presets);
+        assert_eq!(
+            items,
+            vec![
+                ("琴葉 茜".to_string(), "こんにちは".to_string()),
+                ("琴葉 葵".to_string(), "お姉ちゃん".to_string()),
+                ("琴葉 茜".to_string(), "時刻: 10:00".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    #[ignore = "replaces the host's list items; the original rows are restored afterwards"]
+    fn replace_list_replaces_all_rows() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        let original_mode = ai_voice.text_edit_mode()?;
+        ai_voice.set_text_edit_mode(TextEditMode::List)?;
+
+        let original = ai_voice.list_items()?;
+
+        let preset = ai_voice.voice_preset_names()?.remove(0);
+        ai_voice.add_list_item(&preset, "削除される行")?;
+
+        let items = (0..500)
+            .map(|i| ListItem::new(preset.as_str(), format!("{}行目", i + 1)))
+            .collect::<Vec<_>>();
+        ai_voice.replace_list(&items)?;
+
+        let count = ai_voice.list_count()?;
+        let first = ai_voice.list_sentence_at(0)?;
+        let last = ai_voice.list_sentence_at(499)?;
+
+        // 元のリストとテキストの編集モードに戻す
+        ai_voice.replace_list(&original)?;
+        ai_voice.set_text_edit_mode(original_mode)?;
+
+        assert_eq!(count, 500);
+        assert_eq!(first, "1行目");
+        assert_eq!(last, "500行目");
+
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "replaces the host's list items; the original rows are restored afterwards"]
+    fn list_selection_indices_round_trip() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        let original_mode = ai_voice.text_edit_mode()?;
+        ai_voice.set_text_edit_mode(TextEditMode::List)?;
+
+        let original_selection = ai_voice.list_selection_indices()?;
+        let original = ai_voice.list_items()?;
+
+        let preset = ai_voice.voice_preset_names()?.remove(0);
+        let items = (0..5)
+            .map(|i| ListItem::new(preset.as_str(), format!("{}行目", i + 1)))
+            .collect::<Vec<_>>();
+        ai_voice.replace_list(&items)?;
+
+        ai_voice.set_list_selection_indices(vec![0, 2, 4])?;
+        let mut indices = ai_voice.list_selection_indices()?;
+        indices.sort();
+
+        // 元のリスト・選択状態・テキストの編集モードに戻す
+        ai_voice.replace_list(&original)?;
+        ai_voice.set_list_selection_indices(original_selection)?;
+        ai_voice.set_text_edit_mode(original_mode)?;
+
+        assert_eq!(indices, [0, 2, 4]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+/// COM メソッドの呼び出しをラップします。
+///
+/// `metrics` 機能が有効な場合、呼び出し回数と所要時間を
+/// `ai_voice.<name>.calls` / `ai_voice.<name>.latency` として記録します。
+/// 機能が無効な場合は呼び出しをそのまま評価します。
+///
+/// 最初の引数に `self` を指定した場合は、失敗したときに `AiVoice::check_connection` で
+/// ホストプログラムの状態に応じたエラーに変換します。
+macro_rules! com_call {
+    ($self:ident, $name:literal, $call:expr) => {
+        $self.check_connection(com_call!($name, $call))
+    };
+    ($name:literal, $call:expr) => {{
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
+        let result = $call;
+
+        #[cfg(feature = "metrics")]
+        {
+            metrics::counter!(concat!("ai_voice.", $name, ".calls")).increment(1);
+            metrics::histogram!(concat!("ai_voice.", $name, ".latency"))
+                .record(start.elapsed().as_secs_f64());
+        }
+
+        result
+    }};
+}
@@ -0,0 +1,264 @@
+use std::ops::RangeInclusive;
+
+use crate::{AiVoice, MasterControl, Result};
+
+#[derive(Debug, Default, Clone, PartialEq)]
+#[doc = "マスターコントロールの部分更新"]
+pub struct MasterControlPatch {
+    #[doc = "ボリューム"]
+    pub volume: Option<f32>,
+    #[doc = "話速"]
+    pub speed: Option<f32>,
+    #[doc = "高さ"]
+    pub pitch: Option<f32>,
+    #[doc = "抑揚"]
+    pub pitch_range: Option<f32>,
+    #[doc = "短ポーズ(ms)"]
+    pub middle_pause: Option<u16>,
+    #[doc = "長ポーズ(ms)"]
+    pub long_pause: Option<u16>,
+    #[doc = "文末ポーズ(ms)"]
+    pub sentence_pause: Option<u16>,
+}
+
+impl MasterControlPatch {
+    /// `Some` が設定されたフィールドのみを `master_control` に上書きします。
+    ///
+    /// # 引数
+    /// * `master_control` - 上書きする `MasterControl` 構造体
+    ///
+    pub fn apply_to(&self, master_control: &mut MasterControl) {
+        if let Some(volume) = self.volume {
+            master_control.volume = volume;
+        }
+        if let Some(speed) = self.speed {
+            master_control.speed = speed;
+        }
+        if let Some(pitch) = self.pitch {
+            master_control.pitch = pitch;
+        }
+        if let Some(pitch_range) = self.pitch_range {
+            master_control.pitch_range = pitch_range;
+        }
+        if let Some(middle_pause) = self.middle_pause {
+            master_control.middle_pause = middle_pause;
+        }
+        if let Some(long_pause) = self.long_pause {
+            master_control.long_pause = long_pause;
+        }
+        if let Some(sentence_pause) = self.sentence_pause {
+            master_control.sentence_pause = sentence_pause;
+        }
+    }
+}
+
+impl MasterControl {
+    #[doc = "ボリュームの指定可能な範囲"]
+    pub const VOLUME_RANGE: RangeInclusive<f32> = 0.0..=5.0;
+    #[doc = "話速の指定可能な範囲"]
+    pub const SPEED_RANGE: RangeInclusive<f32> = 0.0..=4.0;
+    #[doc = "高さの指定可能な範囲"]
+    pub const PITCH_RANGE: RangeInclusive<f32> = 0.0..=2.0;
+    #[doc = "抑揚の指定可能な範囲"]
+    pub const PITCH_RANGE_RANGE: RangeInclusive<f32> = 0.0..=2.0;
+    #[doc = "短ポーズ(ms)の指定可能な範囲"]
+    pub const MIDDLE_PAUSE_RANGE: RangeInclusive<u16> = 0..=500;
+    #[doc = "長ポーズ(ms)の指定可能な範囲"]
+    pub const LONG_PAUSE_RANGE: RangeInclusive<u16> = 0..=2000;
+    #[doc = "文末ポーズ(ms)の指定可能な範囲"]
+    pub const SENTENCE_PAUSE_RANGE: RangeInclusive<u16> = 0..=10000;
+
+    /// 各項目の値を指定可能な範囲内に制限します。
+    ///
+    /// 範囲は `VOLUME_RANGE` などの定数で定義されており、`apply_master_control` でも同じ範囲に制限されます。
+    ///
+    pub fn clamp(&mut self) {
+        self.volume = clamp_to(self.volume, &Self::VOLUME_RANGE);
+        self.speed = clamp_to(self.speed, &Self::SPEED_RANGE);
+        self.pitch = clamp_to(self.pitch, &Self::PITCH_RANGE);
+        self.pitch_range = clamp_to(self.pitch_range, &Self::PITCH_RANGE_RANGE);
+        self.middle_pause = clamp_to(self.middle_pause, &Self::MIDDLE_PAUSE_RANGE);
+        self.long_pause = clamp_to(self.long_pause, &Self::LONG_PAUSE_RANGE);
+        self.sentence_pause = clamp_to(self.sentence_pause, &Self::SENTENCE_PAUSE_RANGE);
+    }
+}
+
+/// `value` を `range` の範囲内に制限します。
+pub(crate) fn clamp_to<T: PartialOrd + Copy>(value: T, range: &RangeInclusive<T>) -> T {
+    if value < *range.start() {
+        *range.start()
+    } else if value > *range.end() {
+        *range.end()
+    } else {
+        value
+    }
+}
+
+/// マスターコントロールの各値と百分率の対応
+///
+/// いずれも標準値 `1.0` を `100%` とし、値 `x` を `x * 100%` に対応付けます。
+/// 設定時は `VOLUME_RANGE` などの定数の範囲（`apply_master_control` と同じ範囲）に制限されます。
+///
+/// | 項目 | 値の範囲 | 百分率の範囲 |
+/// | --- | --- | --- |
+/// | ボリューム | 0.0 - 5.0 | 0% - 500% |
+/// | 話速 | 0.0 - 4.0 | 0% - 400% |
+/// | 高さ | 0.0 - 2.0 | 0% - 200% |
+/// | 抑揚 | 0.0 - 2.0 | 0% - 200% |
+///
+impl MasterControl {
+    /// ボリュームを百分率で取得します。
+    ///
+    pub fn volume_percent(&self) -> f32 {
+        self.volume * 100.0
+    }
+
+    /// ボリュームを百分率で設定します。
+    ///
+    /// # 引数
+    /// * `percent` - ボリューム（0% - 500%）
+    ///
+    pub fn set_volume_percent(&mut self, percent: f32) {
+        self.volume = clamp_to(percent / 100.0, &Self::VOLUME_RANGE);
+    }
+
+    /// 話速を百分率で取得します。
+    ///
+    pub fn speed_percent(&self) -> f32 {
+        self.speed * 100.0
+    }
+
+    /// 話速を百分率で設定します。
+    ///
+    /// # 引数
+    /// * `percent` - 話速（0% - 400%）
+    ///
+    pub fn set_speed_percent(&mut self, percent: f32) {
+        self.speed = clamp_to(percent / 100.0, &Self::SPEED_RANGE);
+    }
+
+    /// 高さを百分率で取得します。
+    ///
+    pub fn pitch_percent(&self) -> f32 {
+        self.pitch * 100.0
+    }
+
+    /// 高さを百分率で設定します。
+    ///
+    /// # 引数
+    /// * `percent` - 高さ（0% - 200%）
+    ///
+    pub fn set_pitch_percent(&mut self, percent: f32) {
+        self.pitch = clamp_to(percent / 100.0, &Self::PITCH_RANGE);
+    }
+
+    /// 抑揚を百分率で取得します。
+    ///
+    pub fn pitch_range_percent(&self) -> f32 {
+        self.pitch_range * 100.0
+    }
+
+    /// 抑揚を百分率で設定します。
+    ///
+    /// # 引数
+    /// * `percent` - 抑揚（0% - 200%）
+    ///
+    pub fn set_pitch_range_percent(&mut self, percent: f32) {
+        self.pitch_range = clamp_to(percent / 100.0, &Self::PITCH_RANGE_RANGE);
+    }
+}
+
+impl AiVoice {
+    /// マスターコントロールの設定を部分的に更新します。
+    ///
+    /// 現在の設定を取得し、`patch` で `Some` が設定されたフィールドのみを上書きして適用します。
+    /// 適用時の値の制限は `apply_master_control` と同じです。
+    ///
+    /// # 引数
+    /// * `patch` - 適用する `MasterControlPatch` 構造体
+    ///
+    pub fn patch_master_control(&self, patch: &MasterControlPatch) -> Result<()> {
+        let mut master_control = self.master_control()?;
+        patch.apply_to(&mut master_control);
+        self.apply_master_control(&master_control)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::master_control;
+
+    #[test]
+    fn patch_changes_only_speed() {
+        let mut master_control = master_control();
+
+        let patch = MasterControlPatch {
+            speed: Some(1.5),
+            ..Default::default()
+        };
+        patch.apply_to(&mut master_control);
+
+        assert_eq!(master_control.speed, 1.5);
+        assert_eq!(master_control.volume, 1.0);
+        assert_eq!(master_control.pitch, 1.0);
+        assert_eq!(master_control.pitch_range, 1.0);
+        assert_eq!(master_control.middle_pause, 150);
+        assert_eq!(master_control.long_pause, 370);
+        assert_eq!(master_control.sentence_pause, 800);
+    }
+
+    #[test]
+    fn percent_helpers_map_and_clamp() {
+        let mut master_control = master_control();
+        assert_eq!(master_control.speed_percent(), 100.0);
+
+        master_control.set_speed_percent(150.0);
+        assert_eq!(master_control.speed, 1.5);
+
+        master_control.set_pitch_percent(250.0);
+        assert_eq!(master_control.pitch, 2.0);
+
+        master_control.set_volume_percent(-10.0);
+        assert_eq!(master_control.volume, 0.0);
+    }
+
+    #[test]
+    fn clamp_honors_range_constants() {
+        let mut master_control = MasterControl {
+            volume: 10.0,
+            speed: -1.0,
+            pitch: 3.0,
+            pitch_range: -0.5,
+            middle_pause: 1000,
+            long_pause: 5000,
+            sentence_pause: 20000,
+        };
+        master_control.clamp();
+
+        assert_eq!(master_control.volume, *MasterControl::VOLUME_RANGE.end());
+        assert_eq!(master_control.speed, *MasterControl::SPEED_RANGE.start());
+        assert_eq!(master_control.pitch, *MasterControl::PITCH_RANGE.end());
+        assert_eq!(
+            master_control.pitch_range,
+            *MasterControl::PITCH_RANGE_RANGE.start()
+        );
+        assert_eq!(
+            master_control.middle_pause,
+            *MasterControl::MIDDLE_PAUSE_RANGE.end()
+        );
+        assert_eq!(
+            master_control.long_pause,
+            *MasterControl::LONG_PAUSE_RANGE.end()
+        );
+        assert_eq!(
+            master_control.sentence_pause,
+            *MasterControl::SENTENCE_PAUSE_RANGE.end()
+        );
+
+        // 範囲内の値は変更されない
+        let mut in_range = self::master_control();
+        in_range.clamp();
+        assert_eq!(in_range, self::master_control());
+    }
+}
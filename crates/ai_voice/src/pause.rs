@@ -0,0 +1,211 @@
+use std::fmt;
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+use crate::{AiVoiceError, MasterControl, VoicePreset};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[doc = "ポーズの種類"]
+pub enum PauseKind {
+    #[doc = "短ポーズ"]
+    Middle,
+    #[doc = "長ポーズ"]
+    Long,
+    #[doc = "文末ポーズ"]
+    Sentence,
+}
+
+impl PauseKind {
+    /// 指定可能な最大の長さ (ms) を取得します。
+    ///
+    pub fn max(&self) -> u16 {
+        match self {
+            PauseKind::Middle => *MasterControl::MIDDLE_PAUSE_RANGE.end(),
+            PauseKind::Long => *MasterControl::LONG_PAUSE_RANGE.end(),
+            PauseKind::Sentence => *MasterControl::SENTENCE_PAUSE_RANGE.end(),
+        }
+    }
+}
+
+impl fmt::Display for PauseKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PauseKind::Middle => "middle",
+            PauseKind::Long => "long",
+            PauseKind::Sentence => "sentence",
+        })
+    }
+}
+
+macro_rules! pause_ms {
+    ($name:ident, $kind:expr, $label:literal) => {
+        #[doc = concat!("指定可能な範囲内であることが確認された", $label, "の長さ (ms)")]
+        ///
+        /// シリアライズ時はホストプログラムと同じ整数値として扱われます。
+        /// デシリアライズ時も `new` と同じ範囲の検証が行われます。
+        ///
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+        #[serde(transparent)]
+        pub struct $name(u16);
+
+        impl $name {
+            #[doc = concat!($label, "の長さを作成します。")]
+            ///
+            /// # 引数
+            /// * `ms` - ポーズの長さ (ms)
+            ///
+            /// # エラー
+            /// `ms` が指定可能な最大値を超える場合に `AiVoiceError::PauseOutOfRange` を返します。
+            ///
+            pub fn new(ms: u16) -> Result<Self, AiVoiceError> {
+                check_pause($kind, ms).map($name)
+            }
+
+            /// ポーズの長さ (ms) を取得します。
+            ///
+            pub fn get(&self) -> u16 {
+                self.0
+            }
+        }
+
+        impl TryFrom<u16> for $name {
+            type Error = AiVoiceError;
+
+            fn try_from(ms: u16) -> Result<Self, AiVoiceError> {
+                $name::new(ms)
+            }
+        }
+
+        impl From<$name> for u16 {
+            fn from(pause: $name) -> Self {
+                pause.0
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $name::new(u16::deserialize(deserializer)?).map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+/// `ms` が `kind` で指定可能な最大値以下であることを確認します。
+fn check_pause(kind: PauseKind, ms: u16) -> Result<u16, AiVoiceError> {
+    let max = kind.max();
+    if ms > max {
+        return Err(AiVoiceError::PauseOutOfRange {
+            kind,
+            value: ms,
+            max,
+        });
+    }
+
+    Ok(ms)
+}
+
+pause_ms!(MiddlePauseMs, PauseKind::Middle, "短ポーズ");
+pause_ms!(LongPauseMs, PauseKind::Long, "長ポーズ");
+pause_ms!(SentencePauseMs, PauseKind::Sentence, "文末ポーズ");
+
+impl MasterControl {
+    /// 短ポーズを `MiddlePauseMs` として取得します。
+    ///
+    pub fn middle_pause_ms(&self) -> Result<MiddlePauseMs, AiVoiceError> {
+        MiddlePauseMs::new(self.middle_pause)
+    }
+
+    /// 長ポーズを `LongPauseMs` として取得します。
+    ///
+    pub fn long_pause_ms(&self) -> Result<LongPauseMs, AiVoiceError> {
+        LongPauseMs::new(self.long_pause)
+    }
+
+    /// 文末ポーズを `SentencePauseMs` として取得します。
+    ///
+    pub fn sentence_pause_ms(&self) -> Result<SentencePauseMs, AiVoiceError> {
+        SentencePauseMs::new(self.sentence_pause)
+    }
+
+    /// 短ポーズを設定します。
+    ///
+    pub fn set_middle_pause_ms(&mut self, pause: MiddlePauseMs) {
+        self.middle_pause = pause.get();
+    }
+
+    /// 長ポーズを設定します。
+    ///
+    pub fn set_long_pause_ms(&mut self, pause: LongPauseMs) {
+        self.long_pause = pause.get();
+    }
+
+    /// 文末ポーズを設定します。
+    ///
+    pub fn set_sentence_pause_ms(&mut self, pause: SentencePauseMs) {
+        self.sentence_pause = pause.get();
+    }
+}
+
+impl VoicePreset {
+    /// 短ポーズを `MiddlePauseMs` として取得します。
+    ///
+    pub fn middle_pause_ms(&self) -> Result<MiddlePauseMs, AiVoiceError> {
+        MiddlePauseMs::new(self.middle_pause)
+    }
+
+    /// 長ポーズを `LongPauseMs` として取得します。
+    ///
+    pub fn long_pause_ms(&self) -> Result<LongPauseMs, AiVoiceError> {
+        LongPauseMs::new(self.long_pause)
+    }
+
+    /// 短ポーズを設定します。
+    ///
+    pub fn set_middle_pause_ms(&mut self, pause: MiddlePauseMs) {
+        self.middle_pause = pause.get();
+    }
+
+    /// 長ポーズを設定します。
+    ///
+    pub fn set_long_pause_ms(&mut self, pause: LongPauseMs) {
+        self.long_pause = pause.get();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pause_ms_enforces_bounds() {
+        assert_eq!(MiddlePauseMs::new(500).unwrap().get(), 500);
+        assert_eq!(LongPauseMs::new(2000).unwrap().get(), 2000);
+        assert_eq!(SentencePauseMs::new(10000).unwrap().get(), 10000);
+
+        assert!(matches!(
+            MiddlePauseMs::new(501),
+            Err(AiVoiceError::PauseOutOfRange {
+                kind: PauseKind::Middle,
+                value: 501,
+                max: 500
+            })
+        ));
+        assert!(LongPauseMs::new(2001).is_err());
+        assert!(SentencePauseMs::new(10001).is_err());
+        assert!(MiddlePauseMs::try_from(2000).is_err());
+    }
+
+    #[test]
+    fn pause_ms_serializes_as_integer() {
+        let pause = LongPauseMs::new(370).unwrap();
+        assert_eq!(serde_json::to_string(&pause).unwrap(), "370");
+        assert_eq!(serde_json::from_str::<LongPauseMs>("370").unwrap(), pause);
+    }
+
+    #[test]
+    fn pause_ms_deserialization_is_validated() {
+        assert!(serde_json::from_str::<LongPauseMs>("2000").is_ok());
+        assert!(serde_json::from_str::<MiddlePauseMs>("2000").is_err());
+        assert!(serde_json::from_str::<SentencePauseMs>("10001").is_err());
+    }
+}
@@ -0,0 +1,141 @@
+//! 再生の完了を `.await` で待機する機能
+
+use std::{
+    future::{Future, IntoFuture},
+    pin::Pin,
+    time::Instant,
+};
+
+use crate::{stall::PlaybackWaiter, AiVoice, Result};
+
+/// `AiVoice::play_future` で作成する、再生の完了を待機する Future の元となる型
+///
+/// `.await` すると再生を開始し、再生が完了したときに完了します。
+///
+/// # 注意
+/// - 完了は `AiVoiceBuilder::poll_interval` の間隔で `status` を取得して検出します。
+///   待機中は `tokio::time::sleep` でランタイムに制御を戻します。
+/// - 完了前に Future を破棄すると状態の取得を終了します。
+///   `stop_on_drop` を有効にした場合は、あわせて再生を停止します。
+/// - `AiVoice` は `Send` ではないため、この Future も `Send` ではありません。
+///   `tokio::task::LocalSet` や `current_thread` ランタイム上で使用してください。
+///
+pub struct PlayFuture<'a> {
+    ai_voice: &'a AiVoice,
+    stop_on_drop: bool,
+}
+
+impl PlayFuture<'_> {
+    /// 完了前に破棄されたときに再生を停止するかどうかを指定します。
+    ///
+    /// 既定値は `false` です。
+    ///
+    pub fn stop_on_drop(mut self, stop_on_drop: bool) -> Self {
+        self.stop_on_drop = stop_on_drop;
+        self
+    }
+}
+
+/// 破棄されたときに再生を停止するガード
+struct StopGuard<'a> {
+    ai_voice: &'a AiVoice,
+    armed: bool,
+}
+
+impl Drop for StopGuard<'_> {
+    fn drop(&mut self) {
+        if self.armed {
+            let _ = self.ai_voice.stop();
+        }
+    }
+}
+
+impl<'a> IntoFuture for PlayFuture<'a> {
+    type Output = Result<()>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<()>> + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let PlayFuture {
+            ai_voice,
+            stop_on_drop,
+        } = self;
+
+        Box::pin(async move {
+            let deadline = ai_voice.play_until()?;
+            let mut guard = StopGuard {
+                ai_voice,
+                armed: stop_on_drop,
+            };
+
+            let mut waiter = PlaybackWaiter::new(deadline, ai_voice.stall_timeout);
+            while !waiter.step(ai_voice.status()?, Instant::now())? {
+                tokio::time::sleep(ai_voice.poll_interval).await;
+            }
+
+            guard.armed = false;
+            Ok(())
+        })
+    }
+}
+
+impl AiVoice {
+    /// 音声の再生を開始し、再生の完了を待機する `PlayFuture` を返します。
+    ///
+    /// `play_and_wait` の非同期版です。
+    /// `ai_voice.play_future().await` のように使用します。
+    /// 再生は `.await` した時点で開始されます。
+    ///
+    /// # エラー
+    /// `play_and_wait` と同じ条件でエラーを返します。
+    ///
+    pub fn play_future(&self) -> PlayFuture<'_> {
+        PlayFuture {
+            ai_voice: self,
+            stop_on_drop: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{HostStatus, TextEditMode};
+
+    fn prepare(text: &str) -> Result<AiVoice> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        ai_voice.set_text_edit_mode(TextEditMode::Text)?;
+        ai_voice.set_text_force(text)?;
+        Ok(ai_voice)
+    }
+
+    #[tokio::test]
+    async fn play_future_resolves_after_playback() -> Result<()> {
+        let ai_voice = prepare("こんにちは")?;
+
+        ai_voice.play_future().await?;
+        assert_eq!(ai_voice.status()?, HostStatus::Idle);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dropping_play_future_stops_playback() -> Result<()> {
+        let ai_voice = prepare("こんにちは。今日はいい天気ですね。明日も晴れるそうです。")?;
+
+        let result = tokio::time::timeout(
+            Duration::from_millis(500),
+            ai_voice.play_future().stop_on_drop(true),
+        )
+        .await;
+        assert!(result.is_err());
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert_eq!(ai_voice.status()?, HostStatus::Idle);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{stall::PlaybackWaiter, AiVoice, Result, TextEditMode};
+
+/// 複数のテキストを順に再生するキュー
+///
+/// テキスト形式で各項目のボイスプリセットとテキストを設定し、
+/// 再生の完了を待ってから次の項目を再生します。
+/// リスト形式の内容は変更されません。
+///
+#[derive(Debug, Clone, Default)]
+pub struct PlayQueue {
+    items: Vec<(String, String)>,
+    delay: Duration,
+    cancelled: Arc<AtomicBool>,
+}
+
+/// `PlayQueue` の再生を別スレッドから中止するためのハンドル
+#[derive(Debug, Clone)]
+pub struct PlayQueueCanceller {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl PlayQueueCanceller {
+    /// 再生を中止します。
+    ///
+    /// 再生中の項目は停止され、以降の項目は再生されません。
+    ///
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+}
+
+impl PlayQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 再生する項目を追加します。
+    ///
+    /// # 引数
+    /// * `voice_preset_name` - ボイスプリセット名
+    /// * `text` - テキスト
+    ///
+    pub fn push(&mut self, voice_preset_name: &str, text: &str) -> &mut Self {
+        self.items
+            .push((voice_preset_name.to_string(), text.to_string()));
+        self
+    }
+
+    /// 項目の再生が終了してから次の項目を再生するまでの待機時間を設定します。
+    ///
+    pub fn delay(&mut self, delay: Duration) -> &mut Self {
+        self.delay = delay;
+        self
+    }
+
+    /// 項目の数を取得します。
+    ///
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// 項目が存在しないかどうかを取得します。
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// 再生を中止するためのハンドルを取得します。
+    ///
+    pub fn canceller(&self) -> PlayQueueCanceller {
+        PlayQueueCanceller {
+            cancelled: self.cancelled.clone(),
+        }
+    }
+
+    /// 項目を順に再生します。
+    ///
+    /// # 引数
+    /// * `ai_voice` - 再生に使用する `AiVoice`
+    /// * `on_progress` - 各項目の再生を開始するときに、その項目のインデックスを受け取るコールバック
+    ///
+    /// # 戻り値
+    /// 再生を開始した項目の数
+    ///
+    /// # 注意
+    /// - 再生中はテキスト入力形式がテキスト形式に切り替わります。
+    ///   終了後は元のテキスト入力形式、ボイスプリセット、テキストに戻されます。
+    /// - `PlayQueueCanceller::cancel` で中止した場合、再生中の項目は停止されます。
+    ///   中止の要求は次の `play` の開始時に解除されます。
+    ///
+    pub fn play(&self, ai_voice: &AiVoice, mut on_progress: impl FnMut(usize)) -> Result<usize> {
+        self.cancelled.store(false, Ordering::SeqCst);
+
+        let mode = ai_voice.text_edit_mode()?;
+        ai_voice.set_text_edit_mode(TextEditMode::Text)?;
+
+        let preset = ai_voice.current_voice_preset_name()?;
+        let text = ai_voice.text()?;
+
+        let result = self.play_items(ai_voice, &mut on_progress);
+
+        ai_voice.set_current_voice_preset_name(&preset)?;
+        ai_voice.set_text(&text)?;
+        ai_voice.set_text_edit_mode(mode)?;
+
+        result
+    }
+
+    fn play_items(&self, ai_voice: &AiVoice, on_progress: &mut impl FnMut(usize)) -> Result<usize> {
+        for (index, (preset, text)) in self.items.iter().enumerate() {
+            if self.is_cancelled() {
+                return Ok(index);
+            }
+            if index > 0 && !self.delay.is_zero() && !self.sleep(ai_voice, self.delay) {
+                return Ok(index);
+            }
+
+            ai_voice.set_current_voice_preset_name(preset)?;
+            ai_voice.set_text(text)?;
+
+            on_progress(index);
+            let deadline = ai_voice.play_until()?;
+
+            if !self.wait_until_finished(ai_voice, deadline)? {
+                ai_voice.stop()?;
+                return Ok(index + 1);
+            }
+        }
+
+        Ok(self.items.len())
+    }
+
+    /// 再生の完了を待ちます。中止された場合は `false` を返します。
+    ///
+    fn wait_until_finished(&self, ai_voice: &AiVoice, deadline: Instant) -> Result<bool> {
+        let mut waiter = PlaybackWaiter::new(deadline, ai_voice.stall_timeout);
+
+        loop {
+            if self.is_cancelled() {
+                return Ok(false);
+            }
+
+            if waiter.step(ai_voice.status()?, Instant::now())? {
+                return Ok(true);
+            }
+
+            thread::sleep(ai_voice.poll_interval);
+        }
+    }
+
+    /// 中止を確認しながら待機します。中止された場合は `false` を返します。
+    fn sleep(&self, ai_voice: &AiVoice, duration: Duration) -> bool {
+        let deadline = Instant::now() + duration;
+
+        while Instant::now() < deadline {
+            if self.is_cancelled() {
+                return false;
+            }
+            thread::sleep(
+                ai_voice
+                    .poll_interval
+                    .min(deadline.saturating_duration_since(Instant::now())),
+            );
+        }
+
+        !self.is_cancelled()
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn play_queue_plays_in_order() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        let preset = ai_voice.current_voice_preset_name()?;
+        ai_voice.set_text_force("元のテキスト")?;
+
+        let mut queue = PlayQueue::new();
+        queue
+            .push(&preset, "一つ目")
+            .push(&preset, "二つ目")
+            .delay(Duration::from_millis(200));
+
+        let mut progress = Vec::new();
+        assert_eq!(queue.play(&ai_voice, |index| progress.push(index))?, 2);
+        assert_eq!(progress, vec![0, 1]);
+        assert_eq!(ai_voice.text()?, "元のテキスト");
+
+        Ok(())
+    }
+
+    #[test]
+    fn cancelled_queue_stops_early() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        let preset = ai_voice.current_voice_preset_name()?;
+
+        let mut queue = PlayQueue::new();
+        queue.push(&preset, "一つ目").push(&preset, "二つ目");
+
+        let canceller = queue.canceller();
+        assert_eq!(queue.play(&ai_voice, |_| canceller.cancel())?, 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+//! よく使用される型をまとめて再エクスポートするモジュール
+//!
+//! `use ai_voice::prelude::*;` で一般的な用途に必要な型をインポートできます。
+
+pub use crate::{
+    AiVoice, AiVoiceBuilder, AiVoiceError, ComApartment, HostStatus, MasterControl,
+    MasterControlPatch, MergedVoice, MergedVoiceContainer, OverwritePolicy, Style, StyleName,
+    StylePreset, TextEditMode, VoicePreset,
+};
@@ -0,0 +1,1177 @@
+use std::{collections::VecDeque, ops::RangeInclusive};
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{
+    master_control::clamp_to, AiVoice, AiVoiceError, MasterControl, MergedVoiceContainer, Result,
+    Style, StyleName, VoicePreset,
+};
+
+/// 浮動小数点値の比較に用いる許容誤差
+const EPSILON: f64 = 1e-4;
+
+fn approx_eq_f64(a: f64, b: f64) -> bool {
+    (a - b).abs() <= EPSILON
+}
+
+fn approx_eq_f32(a: f32, b: f32) -> bool {
+    approx_eq_f64(a as f64, b as f64)
+}
+
+/// 範囲の下限と上限を `f64` で取得します。
+fn bounds<T: Into<f64> + Copy>(range: &RangeInclusive<T>) -> (f64, f64) {
+    ((*range.start()).into(), (*range.end()).into())
+}
+
+impl Style {
+    /// スタイル情報が近似的に等しいかどうかを判定します。
+    ///
+    /// 値は浮動小数点の丸め誤差を許容して比較されます。
+    ///
+    pub fn approx_eq(&self, other: &Style) -> bool {
+        self.name == other.name && approx_eq_f64(self.value, other.value)
+    }
+
+    /// スタイル名を `StyleName` 列挙型で取得します。
+    ///
+    /// # 戻り値
+    /// 未知のスタイル名の場合は `None`
+    ///
+    pub fn style_name(&self) -> Option<StyleName> {
+        StyleName::from_code(&self.name)
+    }
+}
+
+impl VoicePreset {
+    /// スタイル情報を `StyleName` の順（喜び、怒り、悲しみ）に並べ替えます。
+    ///
+    /// 未知のスタイル名のスタイル情報は末尾に元の順序のまま配置されます。
+    ///
+    pub fn sort_styles(&mut self) {
+        self.styles
+            .sort_by_key(|style| style.style_name().map_or(usize::MAX, |name| name as usize));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[doc = "`VoicePreset::apply_style_preset` で使用するスタイルの組み合わせ"]
+pub enum StylePreset {
+    #[doc = "すべてのスタイルが0の標準の話し方"]
+    Neutral,
+    #[doc = "明るい話し方（喜び 0.6）"]
+    Cheerful,
+    #[doc = "はしゃいだ話し方（喜び 1.0）"]
+    Excited,
+    #[doc = "苛立った話し方（怒り 0.6）"]
+    Irritated,
+    #[doc = "沈んだ話し方（悲しみ 0.6）"]
+    Somber,
+    #[doc = "泣きそうな話し方（悲しみ 1.0、怒り 0.2）"]
+    Tearful,
+}
+
+impl StylePreset {
+    /// すべての `StylePreset`
+    ///
+    pub const ALL: [StylePreset; 6] = [
+        StylePreset::Neutral,
+        StylePreset::Cheerful,
+        StylePreset::Excited,
+        StylePreset::Irritated,
+        StylePreset::Somber,
+        StylePreset::Tearful,
+    ];
+
+    /// 各スタイルの値を返します。
+    ///
+    /// # 戻り値
+    /// `StyleName` の順（喜び、怒り、悲しみ）に並んだスタイル名と値の配列
+    ///
+    pub fn values(&self) -> [(StyleName, f64); 3] {
+        let (joy, anger, sadness) = match self {
+            StylePreset::Neutral => (0.0, 0.0, 0.0),
+            StylePreset::Cheerful => (0.6, 0.0, 0.0),
+            StylePreset::Excited => (1.0, 0.0, 0.0),
+            StylePreset::Irritated => (0.0, 0.6, 0.0),
+            StylePreset::Somber => (0.0, 0.0, 0.6),
+            StylePreset::Tearful => (0.0, 0.2, 1.0),
+        };
+        [
+            (StyleName::Joy, joy),
+            (StyleName::Anger, anger),
+            (StyleName::Sadness, sadness),
+        ]
+    }
+}
+
+impl VoicePreset {
+    /// スタイル情報を `preset` の組み合わせに置き換えます。
+    ///
+    /// 値は `STYLE_VALUE_RANGE` の範囲内に制限されます。
+    ///
+    /// # 引数
+    /// * `preset` - 適用するスタイルの組み合わせ
+    ///
+    /// # 注意
+    /// スタイルに対応していないボイスでは、ホストプログラムに書き込んでもスタイルは反映されません。
+    ///
+    pub fn apply_style_preset(&mut self, preset: StylePreset) {
+        self.styles = preset
+            .values()
+            .into_iter()
+            .map(|(name, value)| Style {
+                name: name.code().to_string(),
+                value: clamp_to(value, &Self::STYLE_VALUE_RANGE),
+            })
+            .collect();
+    }
+}
+
+impl MergedVoiceContainer {
+    /// フュージョン情報が等しいかどうかを判定します。
+    ///
+    pub fn approx_eq(&self, other: &MergedVoiceContainer) -> bool {
+        self.base_pitch_voice_name == other.base_pitch_voice_name
+            && self.merged_voices.len() == other.merged_voices.len()
+            && self
+                .merged_voices
+                .iter()
+                .zip(&other.merged_voices)
+                .all(|(a, b)| a.voice_name == b.voice_name)
+    }
+}
+
+impl VoicePreset {
+    /// ボイスプリセットが近似的に等しいかどうかを判定します。
+    ///
+    /// 数値パラメータは浮動小数点の丸め誤差を許容して比較されます。
+    /// ホストプログラムとの JSON の往復で生じる微小な差異を無視する場合に使用します。
+    ///
+    pub fn approx_eq(&self, other: &VoicePreset) -> bool {
+        self.preset_name == other.preset_name
+            && self.voice_name == other.voice_name
+            && approx_eq_f32(self.volume, other.volume)
+            && approx_eq_f32(self.speed, other.speed)
+            && approx_eq_f32(self.pitch, other.pitch)
+            && approx_eq_f32(self.pitch_range, other.pitch_range)
+            && self.middle_pause == other.middle_pause
+            && self.long_pause == other.long_pause
+            && self.styles.len() == other.styles.len()
+            && self
+                .styles
+                .iter()
+                .zip(&other.styles)
+                .all(|(a, b)| a.approx_eq(b))
+            && self
+                .merged_voice_container
+                .approx_eq(&other.merged_voice_container)
+    }
+}
+
+impl VoicePreset {
+    /// 人が読み書きしやすいよう、整形した JSON 文字列に変換します。
+    ///
+    pub fn to_json_pretty(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// 空白を含まない JSON 文字列に変換します。
+    ///
+    /// ホストプログラムへ送信する際はこの形式が使用されます。
+    ///
+    pub fn to_json_compact(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// JSON 文字列からボイスプリセットを作成します。
+    ///
+    /// `to_json_pretty` と `to_json_compact` のどちらの出力も読み込めます。
+    ///
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[doc = "ボイスプリセットの値の範囲外の項目"]
+pub struct ValidationIssue {
+    #[doc = "項目名"]
+    pub field: String,
+    #[doc = "設定されている値"]
+    pub value: f64,
+    #[doc = "指定可能な最小値"]
+    pub min: f64,
+    #[doc = "指定可能な最大値"]
+    pub max: f64,
+}
+
+impl VoicePreset {
+    /// 指定されたボイスの標準的な設定のボイスプリセットを作成します。
+    ///
+    /// ボイスプリセット名はボイス名と同じになり、ボリューム・話速・高さ・抑揚は `1.0`、
+    /// 短ポーズは150ms、長ポーズは370msに設定されます。
+    /// スタイル情報とフュージョンされたボイスは空になります。
+    ///
+    /// # 引数
+    /// * `voice_name` - ボイス名
+    ///
+    /// # 注意
+    /// ボイス名が存在するかどうかは確認されません。
+    /// 確認してから作成する場合は `AiVoice::create_default_preset` を使用してください。
+    ///
+    pub fn from_voice(voice_name: &str) -> Self {
+        VoicePreset {
+            preset_name: voice_name.to_string(),
+            voice_name: voice_name.to_string(),
+            volume: 1.0,
+            speed: 1.0,
+            pitch: 1.0,
+            pitch_range: 1.0,
+            middle_pause: 150,
+            long_pause: 370,
+            styles: Vec::new(),
+            merged_voice_container: MergedVoiceContainer {
+                base_pitch_voice_name: voice_name.to_string(),
+                merged_voices: Vec::new(),
+            },
+        }
+    }
+
+    #[doc = "ボリュームの指定可能な範囲"]
+    pub const VOLUME_RANGE: RangeInclusive<f32> = MasterControl::VOLUME_RANGE;
+    #[doc = "話速の指定可能な範囲"]
+    pub const SPEED_RANGE: RangeInclusive<f32> = MasterControl::SPEED_RANGE;
+    #[doc = "高さの指定可能な範囲"]
+    pub const PITCH_RANGE: RangeInclusive<f32> = MasterControl::PITCH_RANGE;
+    #[doc = "抑揚の指定可能な範囲"]
+    pub const PITCH_RANGE_RANGE: RangeInclusive<f32> = MasterControl::PITCH_RANGE_RANGE;
+    #[doc = "短ポーズ(ms)の指定可能な範囲"]
+    pub const MIDDLE_PAUSE_RANGE: RangeInclusive<u16> = MasterControl::MIDDLE_PAUSE_RANGE;
+    #[doc = "長ポーズ(ms)の指定可能な範囲"]
+    pub const LONG_PAUSE_RANGE: RangeInclusive<u16> = MasterControl::LONG_PAUSE_RANGE;
+    #[doc = "スタイルの値の指定可能な範囲"]
+    pub const STYLE_VALUE_RANGE: RangeInclusive<f64> = 0.0..=1.0;
+
+    /// 各項目の値が指定可能な範囲内にあるかどうかを検証します。
+    ///
+    /// # 戻り値
+    /// 範囲外の項目の `ValidationIssue` のベクター。すべての項目が範囲内の場合は空
+    ///
+    /// # 注意
+    /// このメソッドは値を変更しません。範囲内に制限する場合は `clamp` を使用してください。
+    ///
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let mut check = |field: String, value: f64, (min, max): (f64, f64)| {
+            if !(min..=max).contains(&value) {
+                issues.push(ValidationIssue {
+                    field,
+                    value,
+                    min,
+                    max,
+                });
+            }
+        };
+
+        check(
+            "volume".to_string(),
+            self.volume as f64,
+            bounds(&Self::VOLUME_RANGE),
+        );
+        check(
+            "speed".to_string(),
+            self.speed as f64,
+            bounds(&Self::SPEED_RANGE),
+        );
+        check(
+            "pitch".to_string(),
+            self.pitch as f64,
+            bounds(&Self::PITCH_RANGE),
+        );
+        check(
+            "pitch_range".to_string(),
+            self.pitch_range as f64,
+            bounds(&Self::PITCH_RANGE_RANGE),
+        );
+        check(
+            "middle_pause".to_string(),
+            self.middle_pause as f64,
+            bounds(&Self::MIDDLE_PAUSE_RANGE),
+        );
+        check(
+            "long_pause".to_string(),
+            self.long_pause as f64,
+            bounds(&Self::LONG_PAUSE_RANGE),
+        );
+        for style in &self.styles {
+            check(
+                format!("styles[{}]", style.name),
+                style.value,
+                bounds(&Self::STYLE_VALUE_RANGE),
+            );
+        }
+
+        issues
+    }
+
+    /// 各項目の値を指定可能な範囲内に制限します。
+    ///
+    /// 範囲は `VOLUME_RANGE` などの定数で定義されており、`validate` でも同じ範囲で検証されます。
+    ///
+    pub fn clamp(&mut self) {
+        self.volume = clamp_to(self.volume, &Self::VOLUME_RANGE);
+        self.speed = clamp_to(self.speed, &Self::SPEED_RANGE);
+        self.pitch = clamp_to(self.pitch, &Self::PITCH_RANGE);
+        self.pitch_range = clamp_to(self.pitch_range, &Self::PITCH_RANGE_RANGE);
+        self.middle_pause = clamp_to(self.middle_pause, &Self::MIDDLE_PAUSE_RANGE);
+        self.long_pause = clamp_to(self.long_pause, &Self::LONG_PAUSE_RANGE);
+        for style in &mut self.styles {
+            style.value = clamp_to(style.value, &Self::STYLE_VALUE_RANGE);
+        }
+    }
+}
+
+bitflags::bitflags! {
+    /// `VoicePreset::overlay` で複製する項目の集合
+    ///
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct PresetFields: u32 {
+        #[doc = "ボリューム"]
+        const VOLUME = 1 << 0;
+        #[doc = "話速"]
+        const SPEED = 1 << 1;
+        #[doc = "高さ"]
+        const PITCH = 1 << 2;
+        #[doc = "抑揚"]
+        const PITCH_RANGE = 1 << 3;
+        #[doc = "短ポーズ"]
+        const MIDDLE_PAUSE = 1 << 4;
+        #[doc = "長ポーズ"]
+        const LONG_PAUSE = 1 << 5;
+        #[doc = "スタイル情報のリスト"]
+        const STYLES = 1 << 6;
+        #[doc = "フュージョン情報"]
+        const FUSION = 1 << 7;
+    }
+}
+
+impl VoicePreset {
+    /// `template` の指定された項目をこのボイスプリセットに複製します。
+    ///
+    /// ボイスプリセット名とボイス名は複製されません。
+    ///
+    /// # 引数
+    /// * `template` - 複製元のボイスプリセット
+    /// * `fields` - 複製する項目
+    ///
+    pub fn overlay(&mut self, template: &VoicePreset, fields: PresetFields) {
+        if fields.contains(PresetFields::VOLUME) {
+            self.volume = template.volume;
+        }
+        if fields.contains(PresetFields::SPEED) {
+            self.speed = template.speed;
+        }
+        if fields.contains(PresetFields::PITCH) {
+            self.pitch = template.pitch;
+        }
+        if fields.contains(PresetFields::PITCH_RANGE) {
+            self.pitch_range = template.pitch_range;
+        }
+        if fields.contains(PresetFields::MIDDLE_PAUSE) {
+            self.middle_pause = template.middle_pause;
+        }
+        if fields.contains(PresetFields::LONG_PAUSE) {
+            self.long_pause = template.long_pause;
+        }
+        if fields.contains(PresetFields::STYLES) {
+            self.styles = template.styles.clone();
+        }
+        if fields.contains(PresetFields::FUSION) {
+            self.merged_voice_container = template.merged_voice_container.clone();
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[doc = "ボイスプリセットの変更された項目"]
+pub enum ChangedField {
+    #[doc = "ボイスプリセット名"]
+    PresetName,
+    #[doc = "ボイス名"]
+    VoiceName,
+    #[doc = "ボリューム"]
+    Volume,
+    #[doc = "話速"]
+    Speed,
+    #[doc = "高さ"]
+    Pitch,
+    #[doc = "抑揚"]
+    PitchRange,
+    #[doc = "短ポーズ"]
+    MiddlePause,
+    #[doc = "長ポーズ"]
+    LongPause,
+    #[doc = "指定されたスタイル名のスタイル情報（追加・削除を含む）"]
+    Style(String),
+    #[doc = "フュージョン情報"]
+    MergedVoiceContainer,
+}
+
+impl VoicePreset {
+    /// `other` と値が異なる項目を求めます。
+    ///
+    /// 浮動小数点値は `approx_eq` と同じ許容誤差で比較されます。
+    /// スタイル情報はスタイル名で対応付けられ、スタイルごとに報告されます。
+    ///
+    /// # 戻り値
+    /// 値が異なる項目の `ChangedField` のベクター。すべて等しい場合は空
+    ///
+    pub fn changed_fields(&self, other: &VoicePreset) -> Vec<ChangedField> {
+        let mut changed = Vec::new();
+
+        if self.preset_name != other.preset_name {
+            changed.push(ChangedField::PresetName);
+        }
+        if self.voice_name != other.voice_name {
+            changed.push(ChangedField::VoiceName);
+        }
+        if !approx_eq_f32(self.volume, other.volume) {
+            changed.push(ChangedField::Volume);
+        }
+        if !approx_eq_f32(self.speed, other.speed) {
+            changed.push(ChangedField::Speed);
+        }
+        if !approx_eq_f32(self.pitch, other.pitch) {
+            changed.push(ChangedField::Pitch);
+        }
+        if !approx_eq_f32(self.pitch_range, other.pitch_range) {
+            changed.push(ChangedField::PitchRange);
+        }
+        if self.middle_pause != other.middle_pause {
+            changed.push(ChangedField::MiddlePause);
+        }
+        if self.long_pause != other.long_pause {
+            changed.push(ChangedField::LongPause);
+        }
+
+        let find = |styles: &[Style], name: &str| -> Option<f64> {
+            styles.iter().find(|s| s.name == name).map(|s| s.value)
+        };
+        for style in &self.styles {
+            match find(&other.styles, &style.name) {
+                Some(value) if approx_eq_f64(style.value, value) => {}
+                _ => changed.push(ChangedField::Style(style.name.clone())),
+            }
+        }
+        for style in &other.styles {
+            if find(&self.styles, &style.name).is_none() {
+                changed.push(ChangedField::Style(style.name.clone()));
+            }
+        }
+
+        if !self
+            .merged_voice_container
+            .approx_eq(&other.merged_voice_container)
+        {
+            changed.push(ChangedField::MergedVoiceContainer);
+        }
+
+        changed
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+#[doc = "ボイスプリセット集合の差分"]
+pub struct PresetDiff {
+    #[doc = "追加されたボイスプリセット名のリスト"]
+    pub added: Vec<String>,
+    #[doc = "削除されたボイスプリセット名のリスト"]
+    pub removed: Vec<String>,
+    #[doc = "変更されたボイスプリセット名のリスト"]
+    pub modified: Vec<String>,
+}
+
+impl PresetDiff {
+    /// 差分が存在しないかどうかを判定します。
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// 書き込んだボイスプリセットと読み戻したボイスプリセットを比較し、反映されなかった項目を求めます。
+///
+/// 次の差異はホストプログラムによる既知の変換として許容されます。
+/// - 浮動小数点値の丸め誤差（`approx_eq` と同じ許容誤差）
+/// - スタイル情報の並び順
+/// - 書き込んだボイスプリセットに含まれないスタイルが、値 `0.0` で補われること
+fn unapplied_fields(written: &VoicePreset, read_back: &VoicePreset) -> Vec<ChangedField> {
+    written
+        .changed_fields(read_back)
+        .into_iter()
+        .filter(|field| match field {
+            ChangedField::Style(name) => {
+                written.styles.iter().any(|s| &s.name == name)
+                    || read_back
+                        .styles
+                        .iter()
+                        .any(|s| &s.name == name && !approx_eq_f64(s.value, 0.0))
+            }
+            _ => true,
+        })
+        .collect()
+}
+
+/// 2つのボイスプリセット集合の差分を求めます。
+///
+/// ボイスプリセットはボイスプリセット名で対応付けられ、
+/// 変更の有無は `VoicePreset::approx_eq` で判定されます。
+///
+/// # 引数
+/// * `a` - 変更前のボイスプリセット集合
+/// * `b` - 変更後のボイスプリセット集合
+///
+/// # 戻り値
+/// `PresetDiff` 構造体で表される差分
+///
+pub fn diff_presets(a: &[VoicePreset], b: &[VoicePreset]) -> PresetDiff {
+    let find = |presets: &[VoicePreset], name: &str| -> Option<usize> {
+        presets.iter().position(|p| p.preset_name == name)
+    };
+
+    let mut diff = PresetDiff::default();
+
+    for before in a {
+        match find(b, &before.preset_name) {
+            Some(i) if !before.approx_eq(&b[i]) => diff.modified.push(before.preset_name.clone()),
+            Some(_) => {}
+            None => diff.removed.push(before.preset_name.clone()),
+        }
+    }
+
+    for after in b {
+        if find(a, &after.preset_name).is_none() {
+            diff.added.push(after.preset_name.clone());
+        }
+    }
+
+    diff
+}
+
+/// 解析済みのボイスプリセットのキャッシュに保持する最大件数
+pub(crate) const PRESET_CACHE_CAPACITY: usize = 32;
+
+/// 解析済みのボイスプリセットをボイスプリセット名ごとに保持する LRU キャッシュ
+///
+/// 件数が上限に達した場合、最も長く参照されていないものから破棄されます。
+#[derive(Debug)]
+pub(crate) struct PresetCache {
+    capacity: usize,
+    // 末尾ほど最近参照されたもの
+    entries: VecDeque<VoicePreset>,
+}
+
+impl PresetCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// キャッシュからボイスプリセットを取得し、最近参照されたものとして扱います。
+    pub(crate) fn get(&mut self, preset_name: &str) -> Option<VoicePreset> {
+        let index = self
+            .entries
+            .iter()
+            .position(|p| p.preset_name == preset_name)?;
+        let preset = self.entries.remove(index)?;
+        self.entries.push_back(preset.clone());
+        Some(preset)
+    }
+
+    /// ボイスプリセットをキャッシュに追加します。
+    ///
+    /// 同じ名前のものが既にある場合は置き換えます。
+    pub(crate) fn insert(&mut self, preset: VoicePreset) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.entries.retain(|p| p.preset_name != preset.preset_name);
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(preset);
+    }
+
+    /// キャッシュをすべて破棄します。
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// ボイスプリセット名の並べ替えに用いる照合キーを作成します。
+///
+/// NFKC 正規化で全角・半角の違いを吸収した後、英字を小文字に、カタカナをひらがなに変換します。
+fn collation_key(name: &str) -> String {
+    name.nfkc()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'ァ'..='ヶ' => char::from_u32(c as u32 - 0x60).unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
+
+/// ボイスプリセット名を照合キーの順に並べ替え、重複を取り除きます。
+fn sort_preset_names(mut names: Vec<String>) -> Vec<String> {
+    names.sort_by_cached_key(|name| (collation_key(name), name.clone()));
+    names.dedup();
+    names
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[doc = "`upsert_voice_preset` の結果"]
+pub enum UpsertOutcome {
+    #[doc = "新規ボイスプリセットを作成した"]
+    Created,
+    #[doc = "既存のボイスプリセットを更新した"]
+    Updated,
+}
+
+impl AiVoice {
+    /// 並べ替えて重複を取り除いたボイスプリセット名のリストを取得します。
+    ///
+    /// 名前は次の照合順で並べ替えられます。
+    /// 1. NFKC 正規化と英字の小文字への変換で、全角・半角と大文字・小文字の違いを無視する
+    /// 2. カタカナをひらがなとみなし、かなを五十音順（清音・濁音・半濁音の順）に並べる
+    /// 3. 1と2で等しい名前は、元の文字列のコードポイント順に並べる
+    ///
+    /// # 戻り値
+    /// ボイスプリセット名のベクター
+    ///
+    /// # 注意
+    /// - 漢字は読みではなくコードポイント順に並びます。
+    /// - 取り除かれるのは完全に一致する名前のみです。
+    /// - `voice_preset_names` はホストプログラムが返した順序のまま取得します。
+    ///
+    pub fn voice_preset_names_sorted(&self) -> Result<Vec<String>> {
+        Ok(sort_preset_names(self.voice_preset_names()?))
+    }
+
+    /// ボイスプリセットを作成または更新します。
+    ///
+    /// `preset_name` のボイスプリセットが既に存在する場合は `set_voice_preset` で更新し、
+    /// 存在しない場合は `add_voice_preset` で作成します。
+    ///
+    /// # 引数
+    /// * `voice_preset` - 作成または更新する`VoicePreset`構造体
+    ///
+    /// # 戻り値
+    /// `UpsertOutcome` 列挙型で表される、作成と更新のどちらを行ったか
+    ///
+    pub fn upsert_voice_preset(&self, voice_preset: &VoicePreset) -> Result<UpsertOutcome> {
+        if self.preset_exists(&voice_preset.preset_name)? {
+            self.set_voice_preset(voice_preset)?;
+            Ok(UpsertOutcome::Updated)
+        } else {
+            self.add_voice_preset_force(voice_preset)?;
+            Ok(UpsertOutcome::Created)
+        }
+    }
+
+    /// 既存のボイスプリセットに指定された設定を適用し、反映されたことを確認します。
+    ///
+    /// `set_voice_preset` で書き込んだ後、キャッシュを使用せずに読み戻して比較します。
+    ///
+    /// # 引数
+    /// * `voice_preset` - 適用する`VoicePreset`構造体
+    ///
+    /// # エラー
+    /// 読み戻した値が書き込んだ値と異なる項目がある場合に、最初の項目を示す
+    /// `AiVoiceError::PresetNotApplied` を返します。
+    /// 次の差異はホストプログラムによる既知の変換として許容されます。
+    /// - 浮動小数点値の丸め誤差（`VoicePreset::approx_eq` と同じ許容誤差）
+    /// - スタイル情報の並び順
+    /// - 書き込んだボイスプリセットに含まれないスタイルが、値 `0.0` で補われること
+    ///
+    /// # 注意
+    /// 範囲外の値がホストプログラムによって制限された場合は反映されなかったものとみなされます。
+    /// 事前に `VoicePreset::clamp` で制限しておくと、この確認に失敗しません。
+    ///
+    pub fn set_voice_preset_verified(&self, voice_preset: &VoicePreset) -> Result<()> {
+        self.set_voice_preset(voice_preset)?;
+
+        let read_back = self.voice_preset_uncached(&voice_preset.preset_name)?;
+        if let Some(field) = unapplied_fields(voice_preset, &read_back)
+            .into_iter()
+            .next()
+        {
+            return Err(AiVoiceError::PresetNotApplied { field });
+        }
+
+        Ok(())
+    }
+
+    /// すべてのボイスプリセットに対して `f` を呼び出し、変更されたものを書き戻します。
+    ///
+    /// 各ボイスプリセットを取得して複製を `f` に渡し、`f` が `true` を返した場合は
+    /// `set_voice_preset` で書き戻します。
+    ///
+    /// # 引数
+    /// * `f` - ボイスプリセットを変更する関数。変更した場合は `true` を返す
+    ///
+    /// # 戻り値
+    /// 書き戻したボイスプリセットの数
+    ///
+    /// # エラー
+    /// 取得・`f`・書き戻しのいずれかで失敗したボイスプリセットがある場合、
+    /// 残りのボイスプリセットの処理を続けた後に `AiVoiceError::PresetBatchFailed` を返します。
+    /// ボイスプリセット名の一覧の取得に失敗した場合は、そのエラーを返します。
+    ///
+    pub fn for_each_preset(
+        &self,
+        mut f: impl FnMut(&mut VoicePreset) -> Result<bool>,
+    ) -> Result<usize> {
+        let mut modified = 0;
+        let mut failures = Vec::new();
+
+        for preset_name in self.voice_preset_names()? {
+            let result = self
+                .voice_preset(&preset_name)
+                .and_then(|mut voice_preset| {
+                    if f(&mut voice_preset)? {
+                        self.set_voice_preset(&voice_preset)?;
+                        return Ok(true);
+                    }
+                    Ok(false)
+                });
+
+            match result {
+                Ok(true) => modified += 1,
+                Ok(false) => {}
+                Err(e) => failures.push((preset_name, format!("{:#}", e))),
+            }
+        }
+
+        if !failures.is_empty() {
+            return Err(AiVoiceError::PresetBatchFailed { modified, failures });
+        }
+
+        Ok(modified)
+    }
+
+    /// 指定されたボイスの標準的な設定のボイスプリセットを作成します。
+    ///
+    /// 設定値は `VoicePreset::from_voice` と同じです。
+    ///
+    /// # 引数
+    /// * `voice_name` - ボイス名
+    /// * `preset_name` - 作成するボイスプリセットの名前
+    ///
+    /// # 戻り値
+    /// 作成した`VoicePreset`構造体
+    ///
+    /// # エラー
+    /// `voice_name` が `voice_names` に含まれない場合に `AiVoiceError::VoiceNotFound` を、
+    /// 同じ名前のボイスプリセットが既に存在する場合に `AiVoiceError::PresetAlreadyExists` を返します。
+    ///
+    pub fn create_default_preset(
+        &self,
+        voice_name: &str,
+        preset_name: &str,
+    ) -> Result<VoicePreset> {
+        if !self.voice_names()?.iter().any(|name| name == voice_name) {
+            return Err(AiVoiceError::VoiceNotFound(voice_name.to_string()));
+        }
+
+        let voice_preset = VoicePreset {
+            preset_name: preset_name.to_string(),
+            ..VoicePreset::from_voice(voice_name)
+        };
+        self.add_voice_preset(&voice_preset)?;
+
+        Ok(voice_preset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::voice_preset as preset;
+
+    #[test]
+    fn diff_presets_reports_all_categories() {
+        let a = vec![preset("removed"), preset("modified"), preset("rounded")];
+
+        let mut modified = preset("modified");
+        modified.speed = 1.5;
+        let mut rounded = preset("rounded");
+        rounded.pitch += 1e-6;
+        rounded.styles[0].value += 1e-9;
+        let b = vec![modified, rounded, preset("added")];
+
+        let diff = diff_presets(&a, &b);
+        assert_eq!(diff.added, vec!["added"]);
+        assert_eq!(diff.removed, vec!["removed"]);
+        assert_eq!(diff.modified, vec!["modified"]);
+    }
+
+    #[test]
+    fn validate_reports_out_of_range_fields() {
+        let mut preset = preset("invalid");
+        preset.speed = 4.5;
+        preset.middle_pause = 600;
+        preset.styles[1].value = 1.2;
+
+        let fields = preset
+            .validate()
+            .into_iter()
+            .map(|issue| issue.field)
+            .collect::<Vec<_>>();
+        assert_eq!(fields, vec!["speed", "middle_pause", "styles[A]"]);
+
+        preset.clamp();
+        assert!(preset.validate().is_empty());
+        assert_eq!(preset.speed, 4.0);
+        assert_eq!(preset.middle_pause, 500);
+        assert_eq!(preset.styles[1].value, 1.0);
+    }
+
+    #[test]
+    fn sort_styles_orders_by_style_name() {
+        let mut preset = preset("sorted");
+        preset.styles = ["X", "S", "J", "A"]
+            .iter()
+            .map(|name| Style {
+                name: name.to_string(),
+                value: 0.0,
+            })
+            .collect();
+
+        preset.sort_styles();
+
+        let names = preset
+            .styles
+            .iter()
+            .map(|style| style.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["J", "A", "S", "X"]);
+    }
+
+    #[test]
+    fn apply_style_preset_replaces_styles() {
+        let mut preset = preset("a");
+        preset.apply_style_preset(StylePreset::Tearful);
+
+        let styles = preset
+            .styles
+            .iter()
+            .map(|s| (s.name.as_str(), s.value))
+            .collect::<Vec<_>>();
+        assert_eq!(styles, [("J", 0.0), ("A", 0.2), ("S", 1.0)]);
+
+        for style_preset in StylePreset::ALL {
+            preset.apply_style_preset(style_preset);
+            assert!(preset.validate().is_empty());
+        }
+    }
+
+    #[test]
+    fn sort_preset_names_uses_kana_order() {
+        let names = [
+            "ミク",
+            "あかね",
+            "Zunko",
+            "アオイ",
+            "あかね",
+            "ｷﾘﾀﾝ",
+            "aoi",
+            "がくぽ",
+        ]
+        .map(String::from)
+        .to_vec();
+
+        assert_eq!(
+            sort_preset_names(names),
+            ["aoi", "Zunko", "アオイ", "あかね", "がくぽ", "ｷﾘﾀﾝ", "ミク"]
+        );
+    }
+
+    #[test]
+    fn overlay_copies_only_selected_fields() {
+        let mut template = preset("template");
+        template.pitch = 1.5;
+        template.styles[0].value = 0.8;
+
+        let mut target = preset("target");
+        target.voice_name = "kotonoha_aoi".to_string();
+        target.pitch = 0.9;
+
+        target.overlay(&template, PresetFields::STYLES);
+
+        assert_eq!(target.preset_name, "target");
+        assert_eq!(target.voice_name, "kotonoha_aoi");
+        assert_eq!(target.pitch, 0.9);
+        assert_eq!(target.styles, template.styles);
+    }
+
+    #[test]
+    fn diff_presets_is_empty_for_identical_sets() {
+        let a = vec![preset("a"), preset("b")];
+        let b = vec![preset("b"), preset("a")];
+
+        assert!(diff_presets(&a, &b).is_empty());
+    }
+
+    #[test]
+    fn changed_fields_reports_single_style() {
+        let a = preset("琴葉 茜");
+        let mut b = a.clone();
+        b.styles[1].value = 0.5;
+        b.volume += 1e-6;
+
+        assert_eq!(
+            a.changed_fields(&b),
+            vec![ChangedField::Style("A".to_string())]
+        );
+        assert!(a.changed_fields(&a).is_empty());
+    }
+
+    #[test]
+    fn changed_fields_reports_added_and_removed_styles() {
+        let a = preset("琴葉 茜");
+        let mut b = a.clone();
+        b.styles.remove(0);
+        b.styles.push(Style {
+            name: "X".to_string(),
+            value: 0.0,
+        });
+        b.speed = 2.0;
+
+        assert_eq!(
+            a.changed_fields(&b),
+            vec![
+                ChangedField::Speed,
+                ChangedField::Style("J".to_string()),
+                ChangedField::Style("X".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn json_round_trips_in_both_formats() {
+        let preset = preset("琴葉 茜");
+
+        let pretty = preset.to_json_pretty().unwrap();
+        let compact = preset.to_json_compact().unwrap();
+        assert!(pretty.contains('\n'));
+        assert!(!compact.contains('\n'));
+        assert!(compact.contains("\"PresetName\":\"琴葉 茜\""));
+
+        assert_eq!(VoicePreset::from_json(&pretty).unwrap(), preset);
+        assert_eq!(VoicePreset::from_json(&compact).unwrap(), preset);
+        assert!(VoicePreset::from_json("{}").is_err());
+        assert!(matches!(
+            VoicePreset::from_json("{").unwrap_err(),
+            AiVoiceError::Json(_)
+        ));
+    }
+
+    #[test]
+    fn preset_cache_evicts_least_recently_used() {
+        let mut cache = PresetCache::new(2);
+        cache.insert(preset("a"));
+        cache.insert(preset("b"));
+
+        // "a" を参照すると "b" が最も古くなる
+        assert!(cache.get("a").is_some());
+        cache.insert(preset("c"));
+
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("c").is_some());
+    }
+
+    #[test]
+    fn preset_cache_replaces_and_clears() {
+        let mut cache = PresetCache::new(2);
+        cache.insert(preset("a"));
+
+        let mut updated = preset("a");
+        updated.volume = 2.0;
+        cache.insert(updated.clone());
+        cache.insert(preset("b"));
+
+        assert_eq!(cache.get("a"), Some(updated));
+        assert!(cache.get("b").is_some());
+
+        cache.clear();
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("b").is_none());
+
+        let mut disabled = PresetCache::new(0);
+        disabled.insert(preset("a"));
+        assert!(disabled.get("a").is_none());
+    }
+
+    #[test]
+    fn from_voice_uses_neutral_parameters() {
+        let voice_preset = VoicePreset::from_voice("kotonoha_akane");
+
+        assert_eq!(voice_preset.preset_name, "kotonoha_akane");
+        assert_eq!(voice_preset.voice_name, "kotonoha_akane");
+        assert_eq!(voice_preset.speed, 1.0);
+        assert!(voice_preset.styles.is_empty());
+        assert_eq!(
+            voice_preset.merged_voice_container.base_pitch_voice_name,
+            "kotonoha_akane"
+        );
+        assert!(voice_preset.merged_voice_container.merged_voices.is_empty());
+        assert!(voice_preset.validate().is_empty());
+    }
+
+    #[test]
+    fn create_default_preset_rejects_unknown_voice() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        let e = ai_voice
+            .create_default_preset("ai_voice-rs unknown voice", "ai_voice-rs default preset")
+            .unwrap_err();
+        assert!(matches!(
+            &e,
+            AiVoiceError::VoiceNotFound(name) if name == "ai_voice-rs unknown voice"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unapplied_fields_tolerates_known_coercions() {
+        let mut written = preset("verified");
+        written.styles.retain(|s| s.name == "J");
+        written.styles[0].value = 0.5;
+
+        // ホストプログラムが丸め、スタイルの並べ替え、不足したスタイルの補完を行った場合
+        let mut read_back = preset("verified");
+        read_back.speed += 1e-6;
+        read_back.styles = ["S", "J", "A"]
+            .iter()
+            .map(|name| Style {
+                name: name.to_string(),
+                value: if *name == "J" { 0.5 } else { 0.0 },
+            })
+            .collect();
+        assert!(unapplied_fields(&written, &read_back).is_empty());
+
+        // ホストプログラムが値を制限した場合
+        written.speed = 5.0;
+        read_back.speed = 4.0;
+        read_back.styles[2].value = 0.3;
+        assert_eq!(
+            unapplied_fields(&written, &read_back),
+            vec![ChangedField::Speed, ChangedField::Style("A".to_string())]
+        );
+    }
+
+    #[test]
+    fn for_each_preset_writes_back_only_changed() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        let mut visited = 0;
+        let modified = ai_voice.for_each_preset(|_| {
+            visited += 1;
+            Ok(false)
+        })?;
+
+        assert_eq!(modified, 0);
+        assert_eq!(visited, ai_voice.voice_preset_names()?.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn add_voice_preset_rejects_existing_name() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        let voice_preset = ai_voice.voice_preset(&ai_voice.current_voice_preset_name()?)?;
+
+        // 既存の名前は確認の段階で拒否され、ホストプログラムは呼び出されない
+        let e = ai_voice.add_voice_preset(&voice_preset).unwrap_err();
+        assert!(matches!(
+            &e,
+            AiVoiceError::PresetAlreadyExists(name) if *name == voice_preset.preset_name
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "passes an existing preset name to the host, which may overwrite or duplicate it"]
+    fn add_voice_preset_force_skips_existence_check() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        let voice_preset = ai_voice.voice_preset(&ai_voice.current_voice_preset_name()?)?;
+
+        // 確認を省略した場合はホストプログラムに要求が渡される
+        let result = ai_voice.add_voice_preset_force(&voice_preset);
+
+        // 上書きされた場合に備えて元の内容に戻す
+        ai_voice.set_voice_preset(&voice_preset)?;
+
+        if let Err(e) = result {
+            assert!(!matches!(&e, AiVoiceError::PresetAlreadyExists(_)));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "creates a voice preset that the Editor API cannot remove"]
+    fn upsert_creates_then_updates() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        let mut voice_preset = ai_voice.voice_preset(&ai_voice.current_voice_preset_name()?)?;
+        voice_preset.preset_name = "ai_voice-rs upsert test".to_string();
+
+        // 以前の実行で作成済みの場合は、最後に元の内容に戻す
+        let original = ai_voice
+            .voice_preset_names()?
+            .contains(&voice_preset.preset_name)
+            .then(|| ai_voice.voice_preset(&voice_preset.preset_name))
+            .transpose()?;
+        let expected = if original.is_some() {
+            UpsertOutcome::Updated
+        } else {
+            UpsertOutcome::Created
+        };
+        let first = ai_voice.upsert_voice_preset(&voice_preset)?;
+
+        voice_preset.speed = 1.5;
+        let second = ai_voice.upsert_voice_preset(&voice_preset)?;
+        let applied = ai_voice
+            .voice_preset(&voice_preset.preset_name)?
+            .approx_eq(&voice_preset);
+
+        if let Some(original) = &original {
+            ai_voice.set_voice_preset(original)?;
+        }
+
+        assert_eq!(first, expected);
+        assert_eq!(second, UpsertOutcome::Updated);
+        assert!(applied);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    mem::size_of,
+    thread,
+    time::{Duration, Instant},
+};
+
+use windows::Win32::{
+    Foundation::CloseHandle,
+    System::{
+        Diagnostics::ToolHelp::{
+            CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W,
+            TH32CS_SNAPPROCESS,
+        },
+        Threading::{OpenProcess, TerminateProcess, PROCESS_TERMINATE},
+    },
+};
+
+use crate::{ai_voice::check_transition, AiVoice, HostStatus, Result};
+
+/// ホストプログラムの実行ファイル名
+const HOST_EXECUTABLE_NAME: &str = "AIVoiceEditor.exe";
+
+fn find_process_id(executable_name: &str) -> Result<Option<u32>> {
+    unsafe {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0)?;
+
+        let mut entry = PROCESSENTRY32W {
+            dwSize: size_of::<PROCESSENTRY32W>() as u32,
+            ..Default::default()
+        };
+
+        let mut process_id = None;
+        let mut next = Process32FirstW(snapshot, &mut entry);
+        while next.is_ok() {
+            let len = entry
+                .szExeFile
+                .iter()
+                .position(|&c| c == 0)
+                .unwrap_or(entry.szExeFile.len());
+
+            if String::from_utf16_lossy(&entry.szExeFile[..len])
+                .eq_ignore_ascii_case(executable_name)
+            {
+                process_id = Some(entry.th32ProcessID);
+                break;
+            }
+
+            next = Process32NextW(snapshot, &mut entry);
+        }
+
+        CloseHandle(snapshot)?;
+        Ok(process_id)
+    }
+}
+
+fn kill_process(process_id: u32) -> Result<()> {
+    unsafe {
+        let process = OpenProcess(PROCESS_TERMINATE, false, process_id)?;
+        let result = TerminateProcess(process, 1);
+        CloseHandle(process)?;
+        Ok(result?)
+    }
+}
+
+impl AiVoice {
+    /// ホストプログラムのプロセス ID を取得します。
+    ///
+    /// # 戻り値
+    /// ホストプログラムのプロセス ID。ホストプログラムが起動していない場合は `None`
+    ///
+    /// # 注意
+    /// API はプロセス ID を公開していないため、実行中のプロセスから
+    /// ホストプログラムの実行ファイル名 (`AIVoiceEditor.exe`) に一致するものを探します。
+    ///
+    pub fn host_process_id(&self) -> Result<Option<u32>> {
+        find_process_id(HOST_EXECUTABLE_NAME)
+    }
+
+    /// ホストプログラムを終了し、終了するまで待機します。
+    ///
+    /// `timeout` 以内にホストプログラムが終了しなかった場合、プロセスを強制終了します。
+    ///
+    /// # 引数
+    /// * `timeout` - 強制終了までに待機する時間
+    ///
+    /// # 注意
+    /// 強制終了した場合、ホストプログラムで保存されていないプロジェクト等の変更内容は失われます。
+    /// ホストプログラムで確認メッセージが表示されている場合も強制終了の対象になります。
+    /// 状態は `AiVoiceBuilder::poll_interval` で設定した間隔で取得されます。
+    ///
+    pub fn terminate_host_timeout(&self, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+
+        self.terminate_host()?;
+
+        let mut previous = self.status()?;
+        while previous != HostStatus::NotRunning {
+            if Instant::now() >= deadline {
+                if let Some(process_id) = self.host_process_id()? {
+                    kill_process(process_id)?;
+                }
+
+                return Ok(());
+            }
+
+            thread::sleep(self.poll_interval);
+
+            let current = self.status()?;
+            check_transition("terminate_host_timeout", previous, current);
+            previous = current;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt;
+
+use crate::{MergedVoiceContainer, VoicePreset};
+
+/// 名前などの文字列を伏せて `Debug` 出力するためのラッパー
+///
+/// ボイスプリセット名やボイス名は文字数のみが出力され、
+/// 数値パラメータはそのまま出力されます。
+/// 通常の `Debug` 出力はすべての値を含みます。
+///
+pub struct Redacted<'a, T>(pub &'a T);
+
+struct RedactedStr<'a>(&'a str);
+
+impl fmt::Debug for RedactedStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<redacted: {} chars>", self.0.chars().count())
+    }
+}
+
+impl fmt::Debug for Redacted<'_, VoicePreset> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let preset = self.0;
+        f.debug_struct("VoicePreset")
+            .field("preset_name", &RedactedStr(&preset.preset_name))
+            .field("voice_name", &RedactedStr(&preset.voice_name))
+            .field("volume", &preset.volume)
+            .field("speed", &preset.speed)
+            .field("pitch", &preset.pitch)
+            .field("pitch_range", &preset.pitch_range)
+            .field("middle_pause", &preset.middle_pause)
+            .field("long_pause", &preset.long_pause)
+            .field("styles", &preset.styles)
+            .field(
+                "merged_voice_container",
+                &Redacted(&preset.merged_voice_container),
+            )
+            .finish()
+    }
+}
+
+impl fmt::Debug for Redacted<'_, MergedVoiceContainer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let container = self.0;
+        f.debug_struct("MergedVoiceContainer")
+            .field(
+                "base_pitch_voice_name",
+                &RedactedStr(&container.base_pitch_voice_name),
+            )
+            .field(
+                "merged_voices",
+                &container
+                    .merged_voices
+                    .iter()
+                    .map(|v| RedactedStr(&v.voice_name))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl VoicePreset {
+    /// 名前を伏せて `Debug` 出力するためのラッパーを返します。
+    ///
+    pub fn redacted(&self) -> Redacted<'_, VoicePreset> {
+        Redacted(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+
+    #[test]
+    fn redacted_masks_names_only() {
+        let preset = VoicePreset {
+            volume: 1.5,
+            ..test_fixtures::merged_voice_preset("山田 太郎")
+        };
+
+        let output = format!("{:?}", preset.redacted());
+        assert!(!output.contains("山田"));
+        assert!(!output.contains("kotonoha"));
+        assert!(output.contains("<redacted: 5 chars>"));
+        assert!(output.contains("volume: 1.5"));
+        assert!(output.contains("value: 0.5"));
+    }
+}
@@ -0,0 +1,175 @@
+use std::{thread, time::Duration};
+
+use crate::{AiVoiceError, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[doc = "再試行の間隔の増やし方"]
+pub enum Backoff {
+    #[doc = "毎回同じ間隔で再試行する"]
+    Fixed,
+    #[doc = "再試行ごとに間隔を `factor` 倍にする"]
+    Exponential {
+        #[doc = "間隔に掛ける倍率"]
+        factor: u32,
+        #[doc = "間隔の上限"]
+        max_delay: Duration,
+    },
+}
+
+/// 再試行を伴うメソッドが共通で使用する再試行の方針
+///
+/// `AiVoiceBuilder::retry_policy` で設定します。
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    #[doc = "最初の試行を含む最大試行回数"]
+    pub attempts: u32,
+    #[doc = "最初の再試行までの間隔"]
+    pub base_delay: Duration,
+    #[doc = "再試行の間隔の増やし方"]
+    pub backoff: Backoff,
+}
+
+impl Default for RetryPolicy {
+    /// 500ミリ秒間隔で最大5回試行します。
+    fn default() -> Self {
+        RetryPolicy {
+            attempts: 5,
+            base_delay: Duration::from_millis(500),
+            backoff: Backoff::Fixed,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 毎回同じ間隔で再試行する方針を作成します。
+    ///
+    pub fn fixed(attempts: u32, delay: Duration) -> Self {
+        RetryPolicy {
+            attempts,
+            base_delay: delay,
+            backoff: Backoff::Fixed,
+        }
+    }
+
+    /// 再試行ごとに間隔を2倍にする方針を作成します。
+    ///
+    /// # 引数
+    /// * `attempts` - 最初の試行を含む最大試行回数
+    /// * `base_delay` - 最初の再試行までの間隔
+    /// * `max_delay` - 間隔の上限
+    ///
+    pub fn exponential(attempts: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        RetryPolicy {
+            attempts,
+            base_delay,
+            backoff: Backoff::Exponential {
+                factor: 2,
+                max_delay,
+            },
+        }
+    }
+
+    /// `retry` 回目の再試行の前に待機する時間を求めます。
+    ///
+    /// # 引数
+    /// * `retry` - 1から始まる再試行の回数
+    ///
+    pub fn delay(&self, retry: u32) -> Duration {
+        match self.backoff {
+            Backoff::Fixed => self.base_delay,
+            Backoff::Exponential { factor, max_delay } => {
+                let multiplier = factor.saturating_pow(retry.saturating_sub(1));
+                self.base_delay
+                    .checked_mul(multiplier)
+                    .unwrap_or(max_delay)
+                    .min(max_delay)
+            }
+        }
+    }
+
+    /// 成功するか最大試行回数に達するまで `f` を呼び出します。
+    ///
+    /// `AiVoiceError::HostModalBlocking` は利用者の操作なしには解消しないため、再試行せずに返します。
+    ///
+    /// # 戻り値
+    /// 最後に呼び出した `f` の結果
+    ///
+    pub(crate) fn run<T>(&self, mut f: impl FnMut() -> Result<T>) -> Result<T> {
+        let mut retry = 0;
+        loop {
+            match f() {
+                Err(e) if retry + 1 < self.attempts && is_retryable(&e) => {
+                    retry += 1;
+                    thread::sleep(self.delay(retry));
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+fn is_retryable(e: &AiVoiceError) -> bool {
+    !matches!(e, AiVoiceError::HostModalBlocking(_))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_delay_is_constant() {
+        let policy = RetryPolicy::fixed(3, Duration::from_millis(200));
+        assert_eq!(policy.delay(1), Duration::from_millis(200));
+        assert_eq!(policy.delay(10), Duration::from_millis(200));
+    }
+
+    #[test]
+    fn exponential_delay_doubles_up_to_max() {
+        let policy =
+            RetryPolicy::exponential(10, Duration::from_millis(100), Duration::from_millis(1000));
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(4), Duration::from_millis(800));
+        assert_eq!(policy.delay(5), Duration::from_millis(1000));
+        assert_eq!(policy.delay(u32::MAX), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn run_stops_after_attempts() {
+        let policy = RetryPolicy::fixed(3, Duration::ZERO);
+
+        let mut calls = 0;
+        let result: Result<()> = policy.run(|| {
+            calls += 1;
+            Err(AiVoiceError::NotConnected)
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 3);
+
+        let mut calls = 0;
+        let result = policy.run(|| {
+            calls += 1;
+            if calls < 2 {
+                return Err(AiVoiceError::NotConnected);
+            }
+            Ok(calls)
+        });
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn run_does_not_retry_modal_blocking() {
+        let policy = RetryPolicy::fixed(3, Duration::ZERO);
+
+        let mut calls = 0;
+        let result: Result<()> = policy.run(|| {
+            calls += 1;
+            Err(AiVoiceError::HostModalBlocking(windows::core::Error::from(
+                windows::Win32::Foundation::RPC_E_CALL_REJECTED,
+            )))
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+}
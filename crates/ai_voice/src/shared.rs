@@ -0,0 +1,176 @@
+use windows::Win32::{
+    Foundation::RPC_E_CHANGED_MODE,
+    System::Com::{CoInitializeEx, CoUninitialize, COINIT_MULTITHREADED},
+};
+use windows_core::AgileReference;
+
+use ai_voice_sys::ITtsControl;
+
+use crate::{ai_voice::SharedParts, AiVoice, AiVoiceError, Result};
+
+/// スレッド間で共有できる `AiVoice` のハンドル
+///
+/// `AiVoice` は生成したスレッドの COM アパートメントに結び付いているため `Send` ではありません。
+/// `SharedAiVoice` は `ITtsControl` をアジャイル参照として保持し、
+/// `get` を呼び出したスレッドのアパートメント向けのプロキシを取得して `AiVoice` を作成します。
+///
+/// アジャイル参照は `RoGetAgileReference` によって作成され、
+/// Global Interface Table（GIT）に登録したクッキーと同じくアパートメントをまたいだマーシャリングを行います。
+/// `DisconnectWatcher` の監視スレッドと同じ方式です。
+///
+/// # 注意
+/// - `get` のたびにマーシャリングによるプロキシの取得が行われます。
+///   スレッドごとに1回呼び出し、得られた `AiVoice` をそのスレッド内で使い回してください。
+/// - 作成元と異なるアパートメントからのメソッド呼び出しはプロキシ経由のプロセス内 RPC となるため、
+///   同じスレッドから直接呼び出す場合よりも遅くなります。
+/// - `get` で得られる `AiVoice` は、作成元の `AiVoice` の `AiVoiceBuilder` の設定を引き継ぎ、
+///   キャッシュや接続状態などの状態を作成元と共有します。
+///   `on_presets_reloaded` のコールバックは `Send` ではないため共有されません。
+///
+#[derive(Clone)]
+pub struct SharedAiVoice {
+    control: AgileReference<ITtsControl>,
+    parts: SharedParts,
+}
+
+impl SharedAiVoice {
+    /// 現在のスレッドで使用できる `AiVoice` を取得します。
+    ///
+    /// 現在のスレッドで COM を MTA として初期化し、プロキシを取得します。
+    /// このメソッドで COM を初期化した場合は、得られた `AiVoice` の破棄時に `CoUninitialize` が呼び出されます。
+    ///
+    /// 現在のスレッドが既に STA として初期化されている場合はそのアパートメントを使用し、
+    /// `CoUninitialize` は呼び出しません。
+    ///
+    /// # エラー
+    /// COM の初期化やプロキシの取得に失敗した場合にエラーを返します。
+    ///
+    pub fn get(&self) -> Result<AiVoice> {
+        unsafe {
+            let manages_com = match CoInitializeEx(None, COINIT_MULTITHREADED) {
+                hr if hr == RPC_E_CHANGED_MODE => false,
+                hr => {
+                    hr.ok().map_err(AiVoiceError::ComInit)?;
+                    true
+                }
+            };
+
+            match self.control.resolve() {
+                Ok(control) => Ok(AiVoice::from_shared_parts(
+                    control,
+                    self.parts.clone(),
+                    manages_com,
+                )),
+                Err(e) => {
+                    if manages_com {
+                        CoUninitialize();
+                    }
+                    Err(e.into())
+                }
+            }
+        }
+    }
+}
+
+impl AiVoice {
+    /// 別のスレッドに渡せる `SharedAiVoice` を作成します。
+    ///
+    /// # 戻り値
+    /// 同じホストプログラムとの接続を共有する `SharedAiVoice`
+    ///
+    /// # 注意
+    /// 詳細は `SharedAiVoice` を参照してください。
+    ///
+    pub fn share(&self) -> Result<SharedAiVoice> {
+        Ok(SharedAiVoice {
+            control: AgileReference::new(&*self.inner.control)?,
+            parts: self.shared_parts(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Barrier, thread, time::Duration};
+
+    use windows::Win32::System::Com::COINIT_APARTMENTTHREADED;
+
+    use super::*;
+
+    #[test]
+    fn shared_status_from_worker_threads() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        let expected = ai_voice.status()?;
+
+        let shared = ai_voice.share()?;
+        let barrier = Barrier::new(8);
+
+        thread::scope(|scope| {
+            let workers = (0..8)
+                .map(|_| {
+                    scope.spawn(|| -> Result<()> {
+                        let ai_voice = shared.get()?;
+                        barrier.wait();
+                        for _ in 0..10 {
+                            assert_eq!(ai_voice.status()?, expected);
+                        }
+                        Ok(())
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            workers
+                .into_iter()
+                .try_for_each(|worker| worker.join().unwrap())
+        })
+    }
+
+    #[test]
+    fn shared_keeps_builder_settings_and_state() -> Result<()> {
+        let poll_interval = Duration::from_millis(20);
+        let ai_voice = AiVoice::builder().poll_interval(poll_interval).build()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+
+        let shared = ai_voice.share()?;
+        thread::scope(|scope| {
+            scope
+                .spawn(|| -> Result<()> {
+                    let ai_voice = shared.get()?;
+                    assert_eq!(ai_voice.poll_interval, poll_interval);
+                    assert!(ai_voice.has_ever_connected());
+                    Ok(())
+                })
+                .join()
+                .unwrap()
+        })?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn shared_get_accepts_single_threaded_apartment() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+        ai_voice.connect()?;
+        let expected = ai_voice.status()?;
+
+        let shared = ai_voice.share()?;
+        thread::scope(|scope| {
+            scope
+                .spawn(|| -> Result<()> {
+                    unsafe { CoInitializeEx(None, COINIT_APARTMENTTHREADED).ok()? };
+
+                    let result = shared.get().and_then(|ai_voice| ai_voice.status());
+
+                    unsafe { CoUninitialize() };
+                    assert_eq!(result?, expected);
+                    Ok(())
+                })
+                .join()
+                .unwrap()
+        })
+    }
+}
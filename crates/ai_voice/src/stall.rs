@@ -0,0 +1,134 @@
+use std::time::{Duration, Instant};
+
+use crate::{AiVoiceError, HostStatus};
+
+/// 再生の開始後、再生が完了するまでホストプログラムの状態を追跡します。
+///
+/// ホストプログラムが処理中になる前に状態を確認してしまうことがあるため、
+/// 処理中の状態を確認するか推定終了時刻を過ぎるまでは完了とみなしません。
+/// 同期・非同期のどちらの待機でも、ポーリングのたびに `step` を呼び出して使用します。
+///
+pub(crate) struct PlaybackWaiter {
+    deadline: Instant,
+    busy: bool,
+    watchdog: StallWatchdog,
+}
+
+impl PlaybackWaiter {
+    /// `deadline` は `play_time` から推定した再生の終了時刻です。
+    pub(crate) fn new(deadline: Instant, stall_timeout: Option<Duration>) -> Self {
+        Self {
+            deadline,
+            busy: false,
+            watchdog: StallWatchdog::new(deadline, stall_timeout),
+        }
+    }
+
+    /// 取得した状態を記録し、再生が完了した場合は `true` を返します。
+    ///
+    /// 停滞を検出した場合は `AiVoiceError::PlaybackStalled` を返します。
+    pub(crate) fn step(&mut self, status: HostStatus, now: Instant) -> Result<bool, AiVoiceError> {
+        if status == HostStatus::Busy {
+            self.busy = true;
+            self.watchdog.observe_busy(now)?;
+            return Ok(false);
+        }
+
+        Ok(self.busy || now >= self.deadline)
+    }
+}
+
+/// 再生中にホストプログラムが処理中の状態から抜け出せなくなったことを検出します。
+///
+/// COM API からは再生位置を取得できないため、`play_time` から推定した再生の終了時刻を
+/// `stall_timeout` 以上過ぎても処理中の状態が続いている場合に停滞とみなします。
+/// `stall_timeout` が `None` の場合は検出を行いません。
+///
+pub(crate) struct StallWatchdog {
+    expected_end: Instant,
+    stall_timeout: Option<Duration>,
+    busy_since: Option<Instant>,
+}
+
+impl StallWatchdog {
+    pub(crate) fn new(expected_end: Instant, stall_timeout: Option<Duration>) -> Self {
+        Self {
+            expected_end,
+            stall_timeout,
+            busy_since: None,
+        }
+    }
+
+    /// ホストプログラムが処理中であることを記録し、停滞していないかを確認します。
+    pub(crate) fn observe_busy(&mut self, now: Instant) -> Result<(), AiVoiceError> {
+        let busy_since = *self.busy_since.get_or_insert(now);
+
+        match self.stall_timeout {
+            Some(stall_timeout) if now >= self.expected_end + stall_timeout => {
+                Err(AiVoiceError::PlaybackStalled {
+                    busy_for: now.duration_since(busy_since),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_stall_after_expected_end_and_grace() {
+        let start = Instant::now();
+        let mut watchdog =
+            StallWatchdog::new(start + Duration::from_secs(3), Some(Duration::from_secs(2)));
+
+        assert!(watchdog.observe_busy(start).is_ok());
+        assert!(watchdog
+            .observe_busy(start + Duration::from_millis(4900))
+            .is_ok());
+        assert!(matches!(
+            watchdog.observe_busy(start + Duration::from_secs(5)),
+            Err(AiVoiceError::PlaybackStalled { busy_for }) if busy_for == Duration::from_secs(5)
+        ));
+    }
+
+    #[test]
+    fn waiter_finishes_after_busy_or_deadline() {
+        let start = Instant::now();
+        let deadline = start + Duration::from_secs(3);
+
+        // 処理中を確認する前は推定終了時刻まで完了とみなさない
+        let mut waiter = PlaybackWaiter::new(deadline, None);
+        assert!(!waiter.step(HostStatus::Idle, start).unwrap());
+        assert!(waiter.step(HostStatus::Idle, deadline).unwrap());
+
+        // 処理中を確認した後は待機中に戻った時点で完了
+        let mut waiter = PlaybackWaiter::new(deadline, None);
+        assert!(!waiter.step(HostStatus::Busy, start).unwrap());
+        assert!(waiter.step(HostStatus::Idle, start).unwrap());
+    }
+
+    #[test]
+    fn waiter_reports_stall() {
+        let start = Instant::now();
+        let mut waiter = PlaybackWaiter::new(start, Some(Duration::from_secs(1)));
+
+        assert!(!waiter.step(HostStatus::Busy, start).unwrap());
+        assert!(matches!(
+            waiter.step(HostStatus::Busy, start + Duration::from_secs(1)),
+            Err(AiVoiceError::PlaybackStalled { .. })
+        ));
+    }
+
+    #[test]
+    fn disabled_without_stall_timeout() {
+        let start = Instant::now();
+        let mut watchdog = StallWatchdog::new(start, None);
+
+        assert!(watchdog
+            .observe_busy(start + Duration::from_secs(3600))
+            .is_ok());
+    }
+}
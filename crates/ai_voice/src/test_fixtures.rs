@@ -0,0 +1,58 @@
+//! 各モジュールのテストで共通して使用するデータ
+
+use crate::{MasterControl, MergedVoice, MergedVoiceContainer, Style, VoicePreset};
+
+/// 各項目が標準値のマスターコントロールを作成します。
+pub(crate) fn master_control() -> MasterControl {
+    MasterControl {
+        volume: 1.0,
+        speed: 1.0,
+        pitch: 1.0,
+        pitch_range: 1.0,
+        middle_pause: 150,
+        long_pause: 370,
+        sentence_pause: 800,
+    }
+}
+
+/// 各項目が標準値で、3つのスタイルの値が `0.0` のボイスプリセットを作成します。
+pub(crate) fn voice_preset(preset_name: &str) -> VoicePreset {
+    VoicePreset {
+        preset_name: preset_name.to_string(),
+        voice_name: "kotonoha_akane".to_string(),
+        volume: 1.0,
+        speed: 1.0,
+        pitch: 1.0,
+        pitch_range: 1.0,
+        middle_pause: 150,
+        long_pause: 370,
+        styles: vec![
+            Style {
+                name: "J".to_string(),
+                value: 0.0,
+            },
+            Style {
+                name: "A".to_string(),
+                value: 0.0,
+            },
+            Style {
+                name: "S".to_string(),
+                value: 0.0,
+            },
+        ],
+        merged_voice_container: MergedVoiceContainer {
+            base_pitch_voice_name: "kotonoha_akane".to_string(),
+            merged_voices: vec![],
+        },
+    }
+}
+
+/// 喜びのスタイルが `0.5` で、別のボイスを合成したボイスプリセットを作成します。
+pub(crate) fn merged_voice_preset(preset_name: &str) -> VoicePreset {
+    let mut voice_preset = voice_preset(preset_name);
+    voice_preset.styles[0].value = 0.5;
+    voice_preset.merged_voice_container.merged_voices = vec![MergedVoice {
+        voice_name: "kotonoha_aoi".to_string(),
+    }];
+    voice_preset
+}
@@ -0,0 +1,289 @@
+use std::{collections::VecDeque, ops::Range};
+
+use crate::{AiVoice, AiVoiceError, Result};
+
+/// 文字列を `char` 単位で `max_chars` 文字までに切り詰めます。
+///
+/// 切り詰めた場合は末尾に `…` を付加します。
+fn truncate_chars(s: &str, max_chars: usize) -> String {
+    match s.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}…", &s[..end]),
+        None => s.to_string(),
+    }
+}
+
+/// UTF-16 のコード単位での範囲を、UTF-8 のバイト単位での範囲に変換します。
+///
+/// # 引数
+/// * `text` - 範囲の対象となる文字列
+/// * `range` - UTF-16 のコード単位での範囲
+///
+/// # 戻り値
+/// `text` に対するバイト単位での範囲。
+/// 範囲が `text` の外にある場合や、サロゲートペアの途中を指している場合は `None`
+///
+pub fn utf16_to_byte_range(text: &str, range: Range<usize>) -> Option<Range<usize>> {
+    let to_byte = |offset: usize| -> Option<usize> {
+        let mut utf16 = 0;
+        for (byte, c) in text.char_indices() {
+            if utf16 == offset {
+                return Some(byte);
+            }
+            if utf16 > offset {
+                return None;
+            }
+            utf16 += c.len_utf16();
+        }
+        (utf16 == offset).then_some(text.len())
+    };
+
+    if range.start > range.end {
+        return None;
+    }
+
+    Some(to_byte(range.start)?..to_byte(range.end)?)
+}
+
+impl AiVoice {
+    /// テキスト形式の入力テキストを、表示用に切り詰めて取得します。
+    ///
+    /// # 引数
+    /// * `max_chars` - 取得する最大文字数
+    ///
+    /// # 戻り値
+    /// `max_chars` 文字までに切り詰めたテキスト。
+    /// 切り詰めた場合は末尾に `…` が付加されます（`…` は文字数に含みません）。
+    ///
+    /// # 注意
+    /// 文字数は `char` 単位で数えるため、マルチバイト文字や
+    /// サロゲートペアで表される文字の途中で切り詰められることはありません。
+    ///
+    pub fn text_preview(&self, max_chars: usize) -> Result<String> {
+        Ok(truncate_chars(&self.text()?, max_chars))
+    }
+
+    /// テキスト形式の選択範囲を、ホストプログラムが扱う UTF-16 のコード単位で取得します。
+    ///
+    /// # 戻り値
+    /// `text_selection_start` から `text_selection_length` 分の範囲
+    ///
+    pub fn selection_range_utf16(&self) -> Result<Range<usize>> {
+        let start = self.text_selection_start()?.max(0) as usize;
+        let length = self.text_selection_length()?.max(0) as usize;
+        Ok(start..start + length)
+    }
+
+    /// テキスト形式の選択範囲を、`text` で取得したテキストに対するバイト単位で取得します。
+    ///
+    /// # 戻り値
+    /// `&text[range]` で選択されている部分文字列を取得できる範囲
+    ///
+    /// # エラー
+    /// 選択範囲がテキストの外にある場合や、サロゲートペアの途中を指している場合にエラーを返します。
+    ///
+    pub fn selection_to_byte_range(&self) -> Result<Range<usize>> {
+        let text = self.text()?;
+        let range = self.selection_range_utf16()?;
+        utf16_to_byte_range(&text, range.clone())
+            .ok_or(AiVoiceError::SelectionNotOnCharBoundary(range))
+    }
+}
+
+/// テキスト形式の入力テキストの変更履歴
+///
+/// `TextHistory::set_text` で行った変更を記録し、`undo` と `redo` で
+/// 記録したテキストをホストプログラムに設定し直します。
+/// 記録する履歴の数は `capacity` までに制限され、古いものから破棄されます。
+///
+#[derive(Debug, Clone)]
+pub struct TextHistory {
+    undo: VecDeque<String>,
+    redo: Vec<String>,
+    capacity: usize,
+}
+
+impl TextHistory {
+    /// 変更履歴を作成します。
+    ///
+    /// # 引数
+    /// * `capacity` - 記録する履歴の最大数
+    ///
+    pub fn new(capacity: usize) -> Self {
+        TextHistory {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            capacity,
+        }
+    }
+
+    /// テキストを設定し、変更前のテキストを履歴に記録します。
+    ///
+    /// # 引数
+    /// * `ai_voice` - テキストを設定する `AiVoice`
+    /// * `value` - 設定するテキスト
+    ///
+    /// # 注意
+    /// 変更前と同じテキストを設定した場合は記録されません。
+    /// 新たに記録すると、やり直しの履歴は破棄されます。
+    ///
+    pub fn set_text(&mut self, ai_voice: &AiVoice, value: &str) -> Result<()> {
+        let previous = ai_voice.text()?;
+        if previous == value {
+            return Ok(());
+        }
+
+        ai_voice.set_text(value)?;
+        self.record(previous);
+        Ok(())
+    }
+
+    /// 直前の変更を取り消します。
+    ///
+    /// # 戻り値
+    /// 取り消す変更が存在した場合は `true`
+    ///
+    pub fn undo(&mut self, ai_voice: &AiVoice) -> Result<bool> {
+        let Some(previous) = self.undo.back() else {
+            return Ok(false);
+        };
+
+        let current = ai_voice.text()?;
+        ai_voice.set_text(previous)?;
+        self.step_back(current);
+        Ok(true)
+    }
+
+    /// 取り消した変更をやり直します。
+    ///
+    /// # 戻り値
+    /// やり直す変更が存在した場合は `true`
+    ///
+    pub fn redo(&mut self, ai_voice: &AiVoice) -> Result<bool> {
+        let Some(next) = self.redo.last() else {
+            return Ok(false);
+        };
+
+        let current = ai_voice.text()?;
+        ai_voice.set_text(next)?;
+        self.step_forward(current);
+        Ok(true)
+    }
+
+    /// 取り消せる変更が存在するかどうかを取得します。
+    ///
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// やり直せる変更が存在するかどうかを取得します。
+    ///
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// 履歴をすべて破棄します。
+    ///
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    fn record(&mut self, previous: String) {
+        self.push_undo(previous);
+        self.redo.clear();
+    }
+
+    fn step_back(&mut self, current: String) {
+        self.undo.pop_back();
+        self.redo.push(current);
+    }
+
+    fn step_forward(&mut self, current: String) {
+        self.redo.pop();
+        self.push_undo(current);
+    }
+
+    fn push_undo(&mut self, text: String) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.undo.len() == self.capacity {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncate_chars_keeps_short_text() {
+        assert_eq!(truncate_chars("こんにちは", 5), "こんにちは");
+        assert_eq!(truncate_chars("", 0), "");
+    }
+
+    #[test]
+    fn truncate_chars_respects_char_boundaries() {
+        assert_eq!(truncate_chars("こんにちは", 2), "こん…");
+        assert_eq!(truncate_chars("𠮷野家で𩸽を食べる", 3), "𠮷野家…");
+        assert_eq!(truncate_chars("𩸽𩸽𩸽", 1), "𩸽…");
+        assert_eq!(truncate_chars("abc", 0), "…");
+    }
+
+    #[test]
+    fn utf16_to_byte_range_handles_surrogate_pairs() {
+        let text = "a𠮷野家b";
+        // UTF-16: a(1) 𠮷(2) 野(1) 家(1) b(1)
+        assert_eq!(utf16_to_byte_range(text, 0..1), Some(0..1));
+        assert_eq!(utf16_to_byte_range(text, 1..3), Some(1..5));
+        assert_eq!(&text[utf16_to_byte_range(text, 1..4).unwrap()], "𠮷野");
+        assert_eq!(
+            utf16_to_byte_range(text, 6..6),
+            Some(text.len()..text.len())
+        );
+
+        assert_eq!(utf16_to_byte_range(text, 2..4), None);
+        assert_eq!(utf16_to_byte_range(text, 0..7), None);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 3..1;
+        assert_eq!(utf16_to_byte_range(text, reversed), None);
+    }
+
+    #[test]
+    fn text_history_undo_redo_sequence() {
+        let mut history = TextHistory::new(10);
+        history.record("".to_string());
+        history.record("a".to_string());
+        // 現在のテキストは "ab"
+
+        assert_eq!(history.undo.back().unwrap(), "a");
+        history.step_back("ab".to_string());
+        assert_eq!(history.undo.back().unwrap(), "");
+        history.step_back("a".to_string());
+        assert!(!history.can_undo());
+
+        assert_eq!(history.redo.last().unwrap(), "a");
+        history.step_forward("".to_string());
+        assert_eq!(history.redo.last().unwrap(), "ab");
+        assert!(history.can_undo());
+
+        history.record("a".to_string());
+        assert!(!history.can_redo());
+        assert_eq!(history.undo, ["", "a"]);
+    }
+
+    #[test]
+    fn text_history_is_bounded() {
+        let mut history = TextHistory::new(2);
+        for text in ["1", "2", "3"] {
+            history.record(text.to_string());
+        }
+        assert_eq!(history.undo, ["2", "3"]);
+
+        let mut history = TextHistory::new(0);
+        history.record("1".to_string());
+        assert!(!history.can_undo());
+    }
+}
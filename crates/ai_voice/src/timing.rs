@@ -0,0 +1,275 @@
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::Serialize;
+
+use crate::{AiVoice, AiVoiceError, ListItem, Result, TextEditMode};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[doc = "リスト形式の1行分のタイミング情報"]
+pub struct TimingEntry {
+    #[doc = "行のインデックス"]
+    pub index: usize,
+    #[doc = "ボイスプリセット名"]
+    pub voice_preset_name: String,
+    #[doc = "テキスト"]
+    pub text: String,
+    #[doc = "開始時刻（ミリ秒）"]
+    pub start_ms: u64,
+    #[doc = "終了時刻（ミリ秒）"]
+    pub end_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[doc = "保存した音声のタイミング情報"]
+pub struct TimingInfo {
+    #[doc = "保存した音声ファイルのパス"]
+    pub path: PathBuf,
+    #[doc = "行ごとのタイミング情報のリスト"]
+    pub entries: Vec<TimingEntry>,
+}
+
+/// 時間を指定されたフレームレートでのフレーム数に変換します。
+///
+/// # 引数
+/// * `duration` - 時間
+/// * `fps` - フレームレート（フレーム/秒）
+///
+/// # 戻り値
+/// フレーム数。端数は最も近い整数に丸められ、ちょうど半分の場合は切り上げられます。
+/// `fps` が正の有限値でない場合は `0`
+///
+pub fn duration_to_frames(duration: Duration, fps: f64) -> u64 {
+    if !(fps.is_finite() && fps > 0.0) {
+        return 0;
+    }
+
+    (duration.as_secs_f64() * fps).round() as u64
+}
+
+/// 時間を指定されたサンプリングレートでのサンプル数に変換します。
+///
+/// # 引数
+/// * `duration` - 時間
+/// * `sample_rate` - サンプリングレート（Hz）
+///
+/// # 戻り値
+/// サンプル数。端数は最も近い整数に丸められ、ちょうど半分の場合は切り上げられます。
+///
+pub fn duration_to_samples(duration: Duration, sample_rate: u32) -> u64 {
+    // 浮動小数点の誤差を避けるため、ナノ秒単位の整数で計算する
+    let samples = (duration.as_nanos() * sample_rate as u128 + 500_000_000) / 1_000_000_000;
+    samples.min(u64::MAX as u128) as u64
+}
+
+/// SRT 形式の時刻 (`HH:MM:SS,mmm`) に変換します。
+fn srt_timestamp(ms: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02},{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        ms % 1000
+    )
+}
+
+/// 各行の再生時間を累積して、開始時刻と終了時刻を求めます。
+fn accumulate(items: Vec<ListItem>, durations: &[Duration]) -> Vec<TimingEntry> {
+    let mut start_ms = 0;
+    items
+        .into_iter()
+        .zip(durations)
+        .enumerate()
+        .map(|(index, (item, duration))| {
+            let end_ms = start_ms + duration.as_millis() as u64;
+            let entry = TimingEntry {
+                index,
+                voice_preset_name: item.voice_preset_name,
+                text: item.text,
+                start_ms,
+                end_ms,
+            };
+            start_ms = end_ms;
+            entry
+        })
+        .collect()
+}
+
+impl TimingInfo {
+    /// SRT 形式の字幕に変換します。
+    ///
+    pub fn to_srt(&self) -> String {
+        let mut srt = String::new();
+        for entry in &self.entries {
+            let _ = write!(
+                srt,
+                "{}\r\n{} --> {}\r\n{}\r\n\r\n",
+                entry.index + 1,
+                srt_timestamp(entry.start_ms),
+                srt_timestamp(entry.end_ms),
+                entry.text
+            );
+        }
+        srt
+    }
+
+    /// SRT 形式の字幕をファイルに書き出します。
+    ///
+    pub fn write_srt(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        fs::write(path, self.to_srt()).map_err(AiVoiceError::file(path))
+    }
+
+    /// JSON 形式でファイルに書き出します。
+    ///
+    pub fn write_json(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json).map_err(AiVoiceError::file(path))
+    }
+}
+
+impl AiVoice {
+    /// 現在のテキストの再生時間を取得します。
+    ///
+    /// # 戻り値
+    /// `play_time` で取得した再生時間。負の値は `0` として扱われます。
+    ///
+    pub fn play_duration(&self) -> Result<Duration> {
+        Ok(Duration::from_millis(self.play_time()?.max(0) as u64))
+    }
+
+    /// 現在のテキストの再生時間を、指定されたフレームレートでのフレーム数で取得します。
+    ///
+    /// # 引数
+    /// * `fps` - フレームレート（フレーム/秒）
+    ///
+    /// # 戻り値
+    /// フレーム数。丸め方は `duration_to_frames` と同じです。
+    ///
+    /// # エラー
+    /// `fps` が正の有限値でない場合にエラーを返します。
+    ///
+    pub fn play_time_frames(&self, fps: f64) -> Result<u64> {
+        if !(fps.is_finite() && fps > 0.0) {
+            return Err(AiVoiceError::InvalidFrameRate(fps));
+        }
+
+        Ok(duration_to_frames(self.play_duration()?, fps))
+    }
+
+    /// 現在のテキストの再生時間を、指定されたサンプリングレートでのサンプル数で取得します。
+    ///
+    /// # 引数
+    /// * `sample_rate` - サンプリングレート（Hz）
+    ///
+    /// # 戻り値
+    /// サンプル数。丸め方は `duration_to_samples` と同じです。
+    ///
+    pub fn play_time_samples(&self, sample_rate: u32) -> Result<u64> {
+        Ok(duration_to_samples(self.play_duration()?, sample_rate))
+    }
+
+    /// リスト形式の全行の音声を保存し、行ごとのタイミング情報を返します。
+    ///
+    /// # 引数
+    /// * `path` - 出力先ファイルパス
+    ///
+    /// # 戻り値
+    /// `TimingInfo` 構造体で表されるタイミング情報。
+    /// 字幕ファイルが必要な場合は `TimingInfo::write_srt` / `TimingInfo::write_json` で書き出してください。
+    ///
+    /// # エラー
+    /// テキスト入力形式がリスト形式でない場合にエラーを返します。
+    ///
+    /// # 注意
+    /// - タイミングは `list_item_durations` で取得した各行の再生時間を累積した推定値です。
+    ///   行間のポーズなどにより、保存された音声とはサンプル単位では一致しません。
+    /// - 処理中は選択状態が変化しますが、処理後は元の選択状態に戻されます。
+    /// - 保存先の扱いは `save_audio_to_file` と同様です。
+    ///
+    pub fn save_audio_with_timing(&self, path: impl AsRef<Path>) -> Result<TimingInfo> {
+        let path = path.as_ref();
+
+        if self.text_edit_mode()? != TextEditMode::List {
+            return Err(AiVoiceError::ListModeRequired);
+        }
+
+        let items = self.list_items()?;
+        let durations = self.list_item_durations()?;
+
+        let selection = self.list_selection_indices()?;
+        let result = self
+            .set_list_selection_range(0, self.list_count()?)
+            .and_then(|_| self.save_audio_to_file(&path.to_string_lossy()));
+        self.set_list_selection_indices(selection)?;
+        result?;
+
+        Ok(TimingInfo {
+            path: path.to_path_buf(),
+            entries: accumulate(items, &durations),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_to_frames_rounds_to_nearest() {
+        assert_eq!(duration_to_frames(Duration::from_millis(1000), 30.0), 30);
+        // 1500ms * 30fps = 45
+        assert_eq!(duration_to_frames(Duration::from_millis(1500), 30.0), 45);
+        // 1010ms * 29.97fps = 30.2697 -> 30
+        assert_eq!(duration_to_frames(Duration::from_millis(1010), 29.97), 30);
+        // 50ms * 30fps = 1.5 -> 2
+        assert_eq!(duration_to_frames(Duration::from_millis(50), 30.0), 2);
+        assert_eq!(duration_to_frames(Duration::from_secs(1), 0.0), 0);
+        assert_eq!(duration_to_frames(Duration::from_secs(1), f64::NAN), 0);
+    }
+
+    #[test]
+    fn duration_to_samples_rounds_to_nearest() {
+        assert_eq!(
+            duration_to_samples(Duration::from_millis(1000), 48000),
+            48000
+        );
+        assert_eq!(duration_to_samples(Duration::from_millis(1), 44100), 44);
+        // 0.5 サンプルは切り上げ
+        assert_eq!(duration_to_samples(Duration::from_micros(50), 10000), 1);
+        assert_eq!(duration_to_samples(Duration::ZERO, 48000), 0);
+    }
+
+    #[test]
+    fn srt_timestamp_formats_hours() {
+        assert_eq!(srt_timestamp(0), "00:00:00,000");
+        assert_eq!(srt_timestamp(3_723_004), "01:02:03,004");
+    }
+
+    #[test]
+    fn timing_info_accumulates_and_formats_srt() {
+        let items = vec![
+            ListItem::new("琴葉 茜", "こんにちは"),
+            ListItem::new("琴葉 葵", "お姉ちゃん"),
+        ];
+        let durations = [Duration::from_millis(1500), Duration::from_millis(800)];
+
+        let timing = TimingInfo {
+            path: PathBuf::from("out.wav"),
+            entries: accumulate(items, &durations),
+        };
+        assert_eq!(timing.entries[1].start_ms, 1500);
+        assert_eq!(timing.entries[1].end_ms, 2300);
+
+        assert_eq!(
+            timing.to_srt(),
+            "1\r\n00:00:00,000 --> 00:00:01,500\r\nこんにちは\r\n\r\n\
+             2\r\n00:00:01,500 --> 00:00:02,300\r\nお姉ちゃん\r\n\r\n"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use std::{fmt, str::FromStr};
+
+use crate::{AiVoice, AiVoiceError, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc = "ホストプログラムのバージョン"]
+pub struct Version {
+    #[doc = "メジャーバージョン"]
+    pub major: u16,
+    #[doc = "マイナーバージョン"]
+    pub minor: u16,
+    #[doc = "ビルド番号"]
+    pub build: u16,
+    #[doc = "リビジョン番号"]
+    pub revision: u16,
+}
+
+impl Version {
+    pub const fn new(major: u16, minor: u16, build: u16, revision: u16) -> Self {
+        Version {
+            major,
+            minor,
+            build,
+            revision,
+        }
+    }
+}
+
+impl FromStr for Version {
+    type Err = AiVoiceError;
+
+    /// `1.4.10.0` のような、ドット区切りで最大4つの数値からなる文字列を解析します。
+    ///
+    /// 省略された部分は `0` として扱われます。
+    ///
+    fn from_str(s: &str) -> Result<Self> {
+        let parts = s
+            .trim()
+            .split('.')
+            .map(|part| part.parse::<u16>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| AiVoiceError::InvalidVersion(s.to_string()))?;
+
+        if parts.is_empty() || parts.len() > 4 {
+            return Err(AiVoiceError::InvalidVersion(s.to_string()));
+        }
+
+        let part = |i: usize| parts.get(i).copied().unwrap_or(0);
+        Ok(Version::new(part(0), part(1), part(2), part(3)))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            self.major, self.minor, self.build, self.revision
+        )
+    }
+}
+
+impl AiVoice {
+    /// ホストプログラムのバージョンを解析して取得します。
+    ///
+    /// # 戻り値
+    /// `Version` 構造体で表されるホストプログラムのバージョン
+    ///
+    /// # エラー
+    /// バージョン文字列の解析に失敗した場合にエラーを返します。
+    ///
+    /// # 注意
+    /// 解析したバージョンはキャッシュされ、2回目以降はホストプログラムに問い合わせません。
+    /// キャッシュは `start_host` / `force_start_host` / `connect` / `terminate_host` の呼び出し時に破棄されるため、
+    /// ホストプログラムを更新して再起動した場合は新しいバージョンが取得されます。
+    ///
+    pub fn version_parsed(&self) -> Result<Version> {
+        let mut cached = self.version.lock().unwrap();
+        if let Some(version) = *cached {
+            return Ok(version);
+        }
+
+        let version = self.version()?.parse()?;
+        *cached = Some(version);
+        Ok(version)
+    }
+
+    /// ホストプログラムのバージョンが指定されたバージョン以上かどうかを取得します。
+    ///
+    /// # 引数
+    /// * `major` - メジャーバージョン
+    /// * `minor` - マイナーバージョン
+    ///
+    /// # 戻り値
+    /// ホストプログラムのバージョンが `major.minor` 以上の場合は `true`
+    ///
+    pub fn version_at_least(&self, major: u16, minor: u16) -> Result<bool> {
+        Ok(self.version_parsed()? >= Version::new(major, minor, 0, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version() {
+        assert_eq!(
+            "1.4.10.0".parse::<Version>().unwrap(),
+            Version::new(1, 4, 10, 0)
+        );
+        assert_eq!("1.3".parse::<Version>().unwrap(), Version::new(1, 3, 0, 0));
+        assert!("".parse::<Version>().is_err());
+        assert!("1.x".parse::<Version>().is_err());
+        assert!("1.2.3.4.5".parse::<Version>().is_err());
+    }
+
+    #[test]
+    fn cached_version_is_forgotten_on_connect() -> Result<()> {
+        let ai_voice = AiVoice::new()?;
+        ai_voice.start_host()?;
+
+        *ai_voice.version.lock().unwrap() = Some(Version::new(0, 0, 0, 1));
+        ai_voice.connect()?;
+
+        assert_eq!(ai_voice.version_parsed()?, ai_voice.version()?.parse()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn version_gate_matrix() {
+        // 機能と最小バージョンの表はテスト用の値。
+        // 境界となるバージョンで `Version` の比較による判定が正しく行われることを検証する
+        let table = [
+            ("feature_a", Version::new(1, 3, 0, 0)),
+            ("feature_b", Version::new(1, 4, 10, 0)),
+        ];
+        let cases = [
+            ("1.0.0.0", vec![]),
+            ("1.2.99.99", vec![]),
+            ("1.3", vec!["feature_a"]),
+            ("1.4.9.99", vec!["feature_a"]),
+            ("1.4.10.0", vec!["feature_a", "feature_b"]),
+            ("2.0.0.0", vec!["feature_a", "feature_b"]),
+        ];
+
+        for (version, expected) in cases {
+            let version = version.parse::<Version>().unwrap();
+            let supported = table
+                .iter()
+                .filter(|&&(_, min_version)| version >= min_version)
+                .map(|&(name, _)| name)
+                .collect::<Vec<_>>();
+            assert_eq!(supported, expected, "version {}", version);
+        }
+    }
+}
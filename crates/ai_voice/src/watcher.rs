@@ -0,0 +1,160 @@
+use std::{
+    sync::mpsc::{self, RecvTimeoutError, Sender},
+    thread::{self, JoinHandle},
+};
+
+use windows::Win32::System::Com::{CoInitializeEx, CoUninitialize, COINIT_MULTITHREADED};
+use windows_core::AgileReference;
+
+use crate::{ai_voice::host_status_from_sys, error, AiVoice, AiVoiceError, HostStatus, Result};
+
+/// `AiVoice::on_disconnect` で開始した監視スレッドのハンドル
+///
+/// `stop` を呼び出すか破棄すると監視を停止します。
+///
+pub struct DisconnectWatcher {
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl DisconnectWatcher {
+    /// 監視を停止し、監視スレッドの終了を待機します。
+    ///
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        // 送信側を破棄すると監視スレッドの待機が解除される
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for DisconnectWatcher {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+fn is_disconnected(status: HostStatus) -> bool {
+    matches!(status, HostStatus::NotConnected | HostStatus::NotRunning)
+}
+
+/// 監視スレッドで取得した `Status` の結果をホストプログラムの状態に変換します。
+///
+/// ホストプログラムの終了による RPC のエラーは `NotRunning` として扱い、
+/// その他のエラーや未知の状態の値は `None` として次の取得まで判定を保留します。
+fn watched_status(status: windows::core::Result<ai_voice_sys::HostStatus>) -> Option<HostStatus> {
+    match status {
+        Ok(status) => host_status_from_sys(status).ok(),
+        Err(e) if error::is_host_gone(&e) => Some(HostStatus::NotRunning),
+        Err(_) => None,
+    }
+}
+
+impl AiVoice {
+    /// ホストプログラムとの接続が解除されたときに呼び出されるコールバックを登録します。
+    ///
+    /// バックグラウンドのスレッドでホストプログラムの状態を定期的に取得し、
+    /// 状態が `Idle`・`Busy` から `NotConnected`・`NotRunning` に変化するたびに
+    /// 変化後の状態を引数として `callback` を1回呼び出します。
+    ///
+    /// # 引数
+    /// * `callback` - 接続が解除されたときに呼び出されるコールバック
+    ///
+    /// # 戻り値
+    /// 監視スレッドの `DisconnectWatcher`。破棄すると監視を停止します。
+    ///
+    /// # エラー
+    /// 監視スレッドで COM の初期化や `ITtsControl` のプロキシの取得に失敗した場合にエラーを返します。
+    ///
+    /// # 注意
+    /// - 状態の取得は `AiVoiceBuilder::poll_interval` の間隔で行われるため、通知は最大でその分遅れます。
+    /// - ホストプログラムが異常終了して RPC のエラーが返された場合は、`NotRunning` として通知します。
+    /// - コールバックは監視スレッド上で呼び出されます。
+    ///
+    pub fn on_disconnect(
+        &self,
+        callback: impl Fn(HostStatus) + Send + 'static,
+    ) -> Result<DisconnectWatcher> {
+        let control = AgileReference::new(&*self.inner.control)?;
+        let mut connected = !is_disconnected(self.status()?);
+        let poll_interval = self.poll_interval;
+
+        let (stop, stopped) = mpsc::channel();
+        let (started, startup) = mpsc::channel::<Result<()>>();
+        let thread = thread::spawn(move || unsafe {
+            if let Err(e) = CoInitializeEx(None, COINIT_MULTITHREADED).ok() {
+                let _ = started.send(Err(AiVoiceError::ComInit(e)));
+                return;
+            }
+
+            match control.resolve() {
+                Ok(control) => {
+                    let _ = started.send(Ok(()));
+
+                    while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(poll_interval) {
+                        let Some(status) = watched_status(control.Status()) else {
+                            continue;
+                        };
+
+                        if connected && is_disconnected(status) {
+                            callback(status);
+                        }
+                        connected = !is_disconnected(status);
+                    }
+                }
+                Err(e) => {
+                    let _ = started.send(Err(e.into()));
+                }
+            }
+
+            CoUninitialize();
+        });
+
+        match startup.recv() {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                let _ = thread.join();
+                return Err(e);
+            }
+            // 開始を通知する前にスレッドが終了した場合は、スレッドのパニックを伝播する
+            Err(_) => match thread.join() {
+                Err(panic) => std::panic::resume_unwind(panic),
+                Ok(()) => unreachable!("the watcher thread always reports its startup"),
+            },
+        }
+
+        Ok(DisconnectWatcher {
+            stop: Some(stop),
+            thread: Some(thread),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use windows::Win32::Foundation::{E_FAIL, RPC_E_DISCONNECTED};
+
+    use super::*;
+
+    #[test]
+    fn host_gone_error_is_reported_as_not_running() {
+        assert_eq!(
+            watched_status(Err(windows::core::Error::from(RPC_E_DISCONNECTED))),
+            Some(HostStatus::NotRunning)
+        );
+        assert_eq!(
+            watched_status(Err(windows::core::Error::from(E_FAIL))),
+            None
+        );
+        assert_eq!(
+            watched_status(Ok(ai_voice_sys::HostStatus(0))),
+            Some(HostStatus::NotRunning)
+        );
+        assert_eq!(watched_status(Ok(ai_voice_sys::HostStatus(7))), None);
+    }
+}